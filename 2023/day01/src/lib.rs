@@ -0,0 +1,116 @@
+use utils::{Day, ParseError, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    data: Vec<String>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let data = s.lines().map(String::from).collect();
+        Ok(Self { data })
+    }
+}
+
+fn starts_with_digit(s: &str) -> Option<u32> {
+    if s.starts_with("one") || s.starts_with("1") {
+        Some(1)
+    } else if s.starts_with("two") || s.starts_with("2") {
+        Some(2)
+    } else if s.starts_with("three") || s.starts_with("3") {
+        Some(3)
+    } else if s.starts_with("four") || s.starts_with("4") {
+        Some(4)
+    } else if s.starts_with("five") || s.starts_with("5") {
+        Some(5)
+    } else if s.starts_with("six") || s.starts_with("6") {
+        Some(6)
+    } else if s.starts_with("seven") || s.starts_with("7") {
+        Some(7)
+    } else if s.starts_with("eight") || s.starts_with("8") {
+        Some(8)
+    } else if s.starts_with("nine") || s.starts_with("9") {
+        Some(9)
+    } else {
+        None
+    }
+}
+
+fn find_digits(s: &str) -> (u32, u32) {
+    let mut ans: Option<(u32, u32)> = None;
+
+    for i in 0..s.len() {
+        if let Some(digit) = starts_with_digit(&s[i..]) {
+            if let Some(ans) = &mut ans {
+                ans.1 = digit;
+            } else {
+                ans = Some((digit, digit))
+            }
+        }
+    }
+
+    ans.unwrap()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Input;
+    type Answer = Option<u32>;
+
+    fn part_1(input: &Input) -> Option<u32> {
+        let find_digit = |x: &char| *x >= '0' && *x <= '9';
+
+        let mut ans = 0;
+
+        for line in input.data.iter() {
+            let first_digit = match line.chars().find(find_digit) {
+                Some(digit) => digit.to_digit(10).unwrap(),
+                None => return None,
+            };
+            let last_digit = line
+                .chars()
+                .rev()
+                .find(find_digit)
+                .unwrap()
+                .to_digit(10)
+                .unwrap();
+            let calibration_value = 10 * first_digit + last_digit;
+            ans += calibration_value;
+        }
+
+        Some(ans)
+    }
+
+    fn part_2(input: &Input) -> Option<u32> {
+        let mut ans = 0;
+
+        for line in input.data.iter() {
+            let digits = find_digits(line);
+            let first_digit = digits.0;
+            let last_digit = digits.1;
+            let calibration_value = 10 * first_digit + last_digit;
+            ans += calibration_value;
+        }
+
+        Some(ans)
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 1,
+    inputs: &[
+        ("day01/sample_input_1.txt", PrintMode::None),
+        ("day01/sample_input_2.txt", PrintMode::None),
+        ("day01/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day01>,
+};
+
+utils::answer_tests! {
+    Day01;
+    sample_input_1 => "day01/sample_input_1.txt",
+    sample_input_2 => "day01/sample_input_2.txt",
+    input => "day01/input.txt",
+}
@@ -0,0 +1,130 @@
+use utils::parse::Line;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, Copy)]
+struct CubeSet {
+    pub r: u32,
+    pub g: u32,
+    pub b: u32,
+}
+
+impl CubeSet {
+    pub fn new() -> Self {
+        Self { r: 0, g: 0, b: 0 }
+    }
+
+    pub fn power(&self) -> u32 {
+        self.r * self.g * self.b
+    }
+
+    fn parse<'a>(line: &Line<'a>, s: &'a str) -> Result<Self, ParseError> {
+        let mut ans = CubeSet::new();
+        for color_str in s.split(", ") {
+            let (num, color) = line.split_once(color_str, " ")?;
+            match color {
+                "red" => ans.r = line.parse(num)?,
+                "green" => ans.g = line.parse(num)?,
+                "blue" => ans.b = line.parse(num)?,
+                _ => {
+                    return Err(line.error(
+                        color,
+                        ParseErrorKind::Other(format!("unsupported color '{color}'")),
+                    ))
+                }
+            }
+        }
+        Ok(ans)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    data: Vec<Vec<CubeSet>>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
+
+        for line in utils::parse::lines(s) {
+            let (_, game_str) = line.split_once(line.text, ": ")?;
+            let mut game = Vec::new();
+            for iteration_str in game_str.split("; ") {
+                game.push(CubeSet::parse(&line, iteration_str)?);
+            }
+            data.push(game);
+        }
+
+        Ok(Self { data })
+    }
+}
+
+fn is_valid_game(game: &[CubeSet], all_cubes: &CubeSet) -> bool {
+    for game_iter in game {
+        if game_iter.r > all_cubes.r || game_iter.b > all_cubes.b || game_iter.g > all_cubes.g {
+            return false;
+        }
+    }
+    true
+}
+
+fn compute_minimum_viable_cube_set(game: &[CubeSet]) -> CubeSet {
+    use std::cmp::max;
+
+    let mut ans = CubeSet::new();
+    for game_iteration in game.iter() {
+        ans.r = max(ans.r, game_iteration.r);
+        ans.g = max(ans.g, game_iteration.g);
+        ans.b = max(ans.b, game_iteration.b);
+    }
+    ans
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Input;
+    type Answer = u32;
+
+    fn part_1(input: &Input) -> u32 {
+        let all_cubes = CubeSet {
+            r: 12,
+            g: 13,
+            b: 14,
+        };
+        let mut ans = 0;
+        for (idx, game) in input.data.iter().enumerate() {
+            if is_valid_game(game, &all_cubes) {
+                ans += idx + 1;
+            }
+        }
+        ans as u32
+    }
+
+    fn part_2(input: &Input) -> u32 {
+        let minimum_viable_cube_sets: Vec<CubeSet> = input
+            .data
+            .iter()
+            .map(|x| compute_minimum_viable_cube_set(x))
+            .collect();
+        let powers: Vec<u32> = minimum_viable_cube_sets.iter().map(|x| x.power()).collect();
+        let ans: u32 = powers.iter().sum();
+        ans
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 2,
+    inputs: &[
+        ("day02/sample_input.txt", PrintMode::None),
+        ("day02/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day02>,
+};
+
+utils::answer_tests! {
+    Day02;
+    sample_input => "day02/sample_input.txt",
+    input => "day02/input.txt",
+}
@@ -1,3 +1,5 @@
+use utils::{Day, ParseError, ParseInput, PrintMode, Solution};
+
 #[derive(Debug, Clone)]
 struct Card {
     winning_numbers: Vec<u32>,
@@ -23,32 +25,24 @@ impl Card {
 }
 
 #[derive(Debug, Clone)]
-struct Input {
+pub struct Input {
     cards: Vec<Card>,
 }
 
-impl From<std::fs::File> for Input {
-    fn from(file: std::fs::File) -> Self {
-        use std::io::{BufRead, BufReader};
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
         let mut cards = Vec::new();
-        for line in BufReader::new(file).lines() {
-            let line = line.expect("Failed to read line.");
-            let (_, numbers) = line.split_once(": ").unwrap();
-            let (winning_numbers, owned_numbers) = numbers.split_once(" | ").unwrap();
-            let winning_numbers = winning_numbers
-                .split_whitespace()
-                .map(|x| x.parse::<u32>().unwrap())
-                .collect();
-            let owned_numbers = owned_numbers
-                .split_whitespace()
-                .map(|x| x.parse::<u32>().unwrap())
-                .collect();
+        for line in utils::parse::lines(s) {
+            let (_, numbers) = line.split_once(line.text, ": ")?;
+            let (winning_numbers, owned_numbers) = line.split_once(numbers, " | ")?;
+            let winning_numbers = line.parse_whitespace_separated(winning_numbers)?;
+            let owned_numbers = line.parse_whitespace_separated(owned_numbers)?;
             cards.push(Card {
                 winning_numbers,
                 owned_numbers,
             });
         }
-        Self { cards }
+        Ok(Self { cards })
     }
 }
 
@@ -69,21 +63,33 @@ impl Input {
     }
 }
 
-fn part_1(input: &Input) -> u32 {
-    input.calculate_points()
-}
+pub struct Day04;
 
-fn part_2(input: &Input) -> u32 {
-    input.advanced_calculate_points()
+impl Solution for Day04 {
+    type Input = Input;
+    type Answer = u32;
+
+    fn part_1(input: &Input) -> u32 {
+        input.calculate_points()
+    }
+
+    fn part_2(input: &Input) -> u32 {
+        input.advanced_calculate_points()
+    }
 }
 
-fn main() {
-    use utils::PrintMode;
-    utils::run::<_, _>(
-        &[
-            ("day04/sample_input.txt", PrintMode::None),
-            ("day04/input.txt", PrintMode::None),
-        ],
-        &[part_1, part_2],
-    );
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 4,
+    inputs: &[
+        ("day04/sample_input.txt", PrintMode::None),
+        ("day04/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day04>,
+};
+
+utils::answer_tests! {
+    Day04;
+    sample_input => "day04/sample_input.txt",
+    input => "day04/input.txt",
 }
@@ -0,0 +1,154 @@
+use utils::interval::{IntervalSet, RangeMap};
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    seeds: Vec<u64>,
+    map_count: usize,
+    /// All the maps composed into one, from seeds to locations.
+    seed_to_location: RangeMap<u64>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut lines = utils::parse::lines(s);
+
+        let line = lines.next_line()?;
+        let (_, seeds) = line.split_once(line.text, ": ")?;
+        let seeds: Vec<u64> = line.parse_whitespace_separated(seeds)?;
+
+        let mut maps: Vec<RangeMap<u64>> = Vec::new();
+
+        for line in lines {
+            if line.text.ends_with(':') {
+                maps.push(RangeMap::new());
+            } else if !line.text.is_empty() {
+                let (dst_start, src_start, count) = {
+                    let values: Vec<u64> = line.parse_whitespace_separated(line.text)?;
+                    if values.len() != 3 {
+                        return Err(line.error(
+                            line.text,
+                            ParseErrorKind::Other(String::from("expected 3 values")),
+                        ));
+                    }
+                    (values[0], values[1], values[2])
+                };
+                let map = maps.last_mut().ok_or_else(|| {
+                    line.error(
+                        line.text,
+                        ParseErrorKind::Other(String::from("range outside of a map")),
+                    )
+                })?;
+                map.insert(src_start..src_start + count, dst_start);
+            }
+        }
+
+        let seed_to_location = maps
+            .iter()
+            .fold(RangeMap::new(), |composed, map| composed.then(map));
+        Ok(Self {
+            seeds,
+            map_count: maps.len(),
+            seed_to_location,
+        })
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!(
+            "{} seeds, {} maps ({} pieces once composed)",
+            self.seeds.len(),
+            self.map_count,
+            self.seed_to_location.pieces().count()
+        ))
+    }
+}
+
+impl Input {
+    /// The seeds of part 2, given as ranges.
+    pub fn seed_ranges(&self) -> IntervalSet<u64> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|x| x[0]..x[0] + x[1])
+            .collect()
+    }
+
+    /// Every seed that ends up in `locations`, whether it is planted or not.
+    pub fn seeds_reaching(&self, locations: std::ops::Range<u64>) -> IntervalSet<u64> {
+        self.seed_to_location
+            .preimage(&IntervalSet::from(locations))
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Input;
+    type Answer = u64;
+
+    fn part_1(input: &Input) -> u64 {
+        input
+            .seeds
+            .iter()
+            .map(|seed| input.seed_to_location.get(*seed))
+            .min()
+            .unwrap()
+    }
+
+    fn part_2(input: &Input) -> u64 {
+        let seeds = input.seed_ranges();
+        let location = input.seed_to_location.map(&seeds).min().unwrap();
+        if utils::tracing() {
+            let reaching = input.seeds_reaching(location..location + 1);
+            utils::trace!(
+                "Seeds reaching location {location}: {:?}",
+                reaching.intersection(&seeds).ranges()
+            );
+        }
+        location
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 5,
+    inputs: &[
+        ("day05/sample_input.txt", PrintMode::None),
+        ("day05/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day05>,
+};
+
+utils::answer_tests! {
+    Day05;
+    sample_input => "day05/sample_input.txt",
+    input => "day05/input.txt",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_are_found_from_their_location() {
+        let input = utils::testing::parse_input::<Day05>(2023, "day05/sample_input.txt");
+        // The locations of the seeds of part 1, from the puzzle statement.
+        for (seed, location) in [(79, 82), (14, 43), (55, 86), (13, 35)] {
+            assert!(input.seeds_reaching(location..location + 1).contains(seed));
+        }
+        let reaching = input.seeds_reaching(46..47);
+        assert_eq!(
+            reaching.intersection(&input.seed_ranges()),
+            IntervalSet::from(82..83)
+        );
+
+        let reaching = input.seeds_reaching(40..60);
+        for seed in 0..200 {
+            let location = input.seed_to_location.get(seed);
+            assert_eq!(
+                reaching.contains(seed),
+                (40..60).contains(&location),
+                "{seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use utils::{Day, ParseError, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut lines = utils::parse::lines(s);
+        let line = lines.next_line()?;
+        let (_, times) = line.split_once(line.text, ":")?;
+        let times = line.parse_whitespace_separated(times)?;
+        let line = lines.next_line()?;
+        let (_, distances) = line.split_once(line.text, ":")?;
+        let distances = line.parse_whitespace_separated(distances)?;
+        Ok(Self { times, distances })
+    }
+}
+
+fn count_solutions(time: u64, distance: u64) -> u64 {
+    let tt = time * time;
+    let d4 = 4 * distance;
+
+    if tt < d4 {
+        0
+    } else {
+        let diff = ((tt - d4) as f64).sqrt();
+        let min = ((time as f64 - diff) / 2.).floor() as u64;
+        let max = ((time as f64 + diff) / 2.).ceil() as u64;
+        max - min - 1
+    }
+}
+
+fn concatenate_numbers(v: &[u64]) -> u64 {
+    v.iter().rev().fold(0, |acc, x| {
+        let mut mult = 1;
+        while mult < acc {
+            mult *= 10;
+        }
+        acc + mult * x
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Input;
+    type Answer = u64;
+
+    fn part_1(input: &Input) -> u64 {
+        input
+            .times
+            .iter()
+            .zip(input.distances.iter())
+            .map(|(x, y)| count_solutions(*x, *y))
+            .product()
+    }
+
+    fn part_2(input: &Input) -> u64 {
+        let time = concatenate_numbers(&input.times);
+        let distance = concatenate_numbers(&input.distances);
+        count_solutions(time, distance)
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 6,
+    inputs: &[
+        ("day06/sample_input.txt", PrintMode::None),
+        ("day06/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day06>,
+};
+
+utils::answer_tests! {
+    Day06;
+    sample_input => "day06/sample_input.txt",
+    input => "day06/input.txt",
+}
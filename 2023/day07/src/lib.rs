@@ -1,3 +1,6 @@
+use utils::parse::Line;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum HandKind {
     FiveOfAKind = 6,
@@ -68,30 +71,44 @@ impl Hand {
             }
         }
     }
-}
 
-impl From<String> for Hand {
-    fn from(s: String) -> Self {
-        let (cards_str, bid_str) = s.split_once(" ").unwrap();
-
-        let cards = cards_str
-            .chars()
-            .map(|x| match x {
-                '2'..='9' => x.to_digit(10).unwrap() as u64,
-                'T' => 10,
-                'J' => 11,
-                'Q' => 12,
-                'K' => 13,
-                'A' => 14,
-                _ => panic!("Unrecognized character"),
-            })
+    fn parse(line: &Line) -> Result<Self, ParseError> {
+        let (cards_str, bid_str) = line.split_once(line.text, " ")?;
+
+        let cards = line
+            .parse_chars::<Card>(cards_str)?
+            .into_iter()
+            .map(|x| x.0)
             .collect::<Vec<u64>>()
             .try_into()
-            .unwrap();
+            .map_err(|_| {
+                line.error(
+                    cards_str,
+                    ParseErrorKind::Other(String::from("a hand must have 5 cards")),
+                )
+            })?;
 
-        let bid = bid_str.parse::<u64>().unwrap();
+        let bid = line.parse(bid_str)?;
 
-        Self { cards, bid }
+        Ok(Self { cards, bid })
+    }
+}
+
+struct Card(u64);
+
+impl TryFrom<char> for Card {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '2'..='9' => Ok(Card(c.to_digit(10).unwrap() as u64)),
+            'T' => Ok(Card(10)),
+            'J' => Ok(Card(11)),
+            'Q' => Ok(Card(12)),
+            'K' => Ok(Card(13)),
+            'A' => Ok(Card(14)),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        }
     }
 }
 
@@ -121,7 +138,7 @@ impl PartialOrd for Hand {
 }
 
 #[derive(Debug, Clone)]
-struct Input {
+pub struct Input {
     hands: Vec<Hand>,
 }
 
@@ -142,37 +159,45 @@ impl Input {
     }
 }
 
-impl From<std::fs::File> for Input {
-    fn from(file: std::fs::File) -> Self {
-        use std::io::{BufRead, BufReader};
-
-        let hands = BufReader::new(file)
-            .lines()
-            .map(Result::unwrap)
-            .map(|x| Hand::from(x))
-            .collect();
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let hands = utils::parse::lines(s)
+            .map(|line| Hand::parse(&line))
+            .collect::<Result<_, _>>()?;
 
-        Self { hands }
+        Ok(Self { hands })
     }
 }
 
-fn part_1(input: &Input) -> u64 {
-    input.clone().compute_score()
-}
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Input;
+    type Answer = u64;
 
-fn part_2(input: &Input) -> u64 {
-    let mut input = input.clone();
-    input.replace_jacks_with_jokers();
-    input.compute_score()
+    fn part_1(input: &Input) -> u64 {
+        input.clone().compute_score()
+    }
+
+    fn part_2(input: &Input) -> u64 {
+        let mut input = input.clone();
+        input.replace_jacks_with_jokers();
+        input.compute_score()
+    }
 }
 
-fn main() {
-    use utils::PrintMode;
-    utils::run::<_, _>(
-        &[
-            ("day07/sample_input.txt", PrintMode::None),
-            ("day07/input.txt", PrintMode::None),
-        ],
-        &[part_1, part_2],
-    );
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 7,
+    inputs: &[
+        ("day07/sample_input.txt", PrintMode::None),
+        ("day07/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day07>,
+};
+
+utils::answer_tests! {
+    Day07;
+    sample_input => "day07/sample_input.txt",
+    input => "day07/input.txt",
 }
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use utils::{Check, Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Left = 0,
+    Right = 1,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    directions: Vec<Direction>,
+    map: HashMap<String, [String; 2]>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut lines = utils::parse::lines(s);
+
+        let line = lines.next_line()?;
+        let directions = line.parse_chars(line.text)?;
+
+        let mut map = HashMap::new();
+        for line in lines {
+            if line.text.is_empty() {
+                continue;
+            }
+            let (start_str, left_and_right_str) = line.split_once(line.text, " = ")?;
+            let left_and_right_str = line.strip_prefix(left_and_right_str, "(")?;
+            let left_and_right_str = line.strip_suffix(left_and_right_str, ")")?;
+            let (left_str, right_str) = line.split_once(left_and_right_str, ", ")?;
+            map.insert(
+                String::from(start_str),
+                [String::from(left_str), String::from(right_str)],
+            );
+        }
+
+        Ok(Self { directions, map })
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!(
+            "{} directions, {} nodes",
+            self.directions.len(),
+            self.map.len()
+        ))
+    }
+}
+
+fn step_forward<'a>(it: u64, current_location: &str, input: &'a Input) -> Option<&'a str> {
+    let direction = input.directions[it as usize % input.directions.len()];
+    match input.map.get(current_location) {
+        Some(entry) => Some(&entry[direction as usize]),
+        None => None,
+    }
+}
+
+fn count_steps_to_solution(current_location: &str, input: &Input) -> Option<u64> {
+    let mut current_location = current_location;
+    let mut i = 0;
+    while !current_location.ends_with("Z") {
+        match step_forward(i, current_location, input) {
+            Some(location) => current_location = location,
+            None => return None,
+        }
+        i += 1;
+    }
+    Some(i)
+}
+
+/// Checks that every ghost first reaches a node ending with Z after some number of steps `n`, and
+/// then again at every multiple of `n`, so that the LCM of the first counts is when they all are on
+/// one. Once the instruction index is back to where it was after `n` steps, which is after `2n`
+/// steps when `n` is a multiple of the number of instructions, being on the same node means that
+/// the ghost loops.
+fn check_cycles(input: &Input) -> Result<(), String> {
+    let mut starts: Vec<&String> = input.map.keys().filter(|x| x.ends_with("A")).collect();
+    if starts.is_empty() {
+        return Err(String::from("no node ends with A"));
+    }
+    starts.sort();
+    // Past this many steps, the ghost is in a state (node and direction) it was already in.
+    let max_steps = (input.directions.len() * input.map.len()) as u64;
+    let directions = input.directions.len() as u64;
+    for start in starts {
+        let mut location = start.as_str();
+        let mut i = 0;
+        while i == 0 || !location.ends_with("Z") {
+            if i > max_steps {
+                return Err(format!("{start} never reaches a node ending with Z"));
+            }
+            location = step_forward(i, location, input)
+                .ok_or_else(|| format!("{location} is not a node"))?;
+            i += 1;
+        }
+        let (first, first_location) = (i, location);
+        for _ in 0..directions / gcd(first, directions) {
+            for _ in 0..first {
+                location = step_forward(i, location, input)
+                    .ok_or_else(|| format!("{location} is not a node"))?;
+                i += 1;
+            }
+            if !location.ends_with("Z") {
+                return Err(format!(
+                    "{start} first reaches a node ending with Z after {first} steps, but not \
+                     after {i} steps"
+                ));
+            }
+        }
+        if location != first_location {
+            return Err(format!(
+                "{start} is on {first_location} after {first} steps, and on {location} after {i} \
+                 steps"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn gcd(mut x: u64, mut y: u64) -> u64 {
+    if y > x {
+        std::mem::swap(&mut x, &mut y);
+    }
+    while y > 0 {
+        let modulo = x % y;
+        x = y;
+        y = modulo;
+    }
+    x
+}
+
+fn lcm(x: u64, y: u64) -> u64 {
+    x * y / gcd(x, y)
+}
+
+fn multi_lcm(v: &[u64]) -> u64 {
+    v[1..].iter().fold(v[0], |acc, x| lcm(acc, *x))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Input;
+    type Answer = Option<u64>;
+
+    fn part_1(input: &Input) -> Option<u64> {
+        count_steps_to_solution("AAA", input)
+    }
+
+    fn part_2(input: &Input) -> Option<u64> {
+        let steps: Vec<u64> = input
+            .map
+            .keys()
+            .filter(|x| x.ends_with("A"))
+            .map(|x| count_steps_to_solution(x, input).unwrap())
+            .collect();
+        Some(multi_lcm(&steps))
+    }
+
+    fn validate(input: &Input) -> Vec<Check> {
+        vec![Check::new(
+            &[2],
+            "each ghost reaches a node ending with Z every n steps, starting after n steps",
+            check_cycles(input),
+        )]
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 8,
+    inputs: &[
+        ("day08/sample_input_1.txt", PrintMode::None),
+        ("day08/sample_input_2.txt", PrintMode::None),
+        ("day08/sample_input_3.txt", PrintMode::None),
+        ("day08/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day08>,
+};
+
+utils::answer_tests! {
+    Day08;
+    sample_input_1 => "day08/sample_input_1.txt",
+    sample_input_2 => "day08/sample_input_2.txt",
+    sample_input_3 => "day08/sample_input_3.txt",
+    input => "day08/input.txt",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_have_cycles() {
+        for path in [
+            "day08/sample_input_1.txt",
+            "day08/sample_input_2.txt",
+            "day08/sample_input_3.txt",
+        ] {
+            let input = utils::testing::parse_input::<Day08>(2023, path);
+            assert_eq!(check_cycles(&input), Ok(()), "{path}");
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use utils::{Day, ParseError, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    sequences: Vec<Vec<i64>>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut sequences = Vec::new();
+        for line in utils::parse::lines(s) {
+            sequences.push(line.parse_whitespace_separated(line.text)?);
+        }
+        Ok(Self { sequences })
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Input;
+    type Answer = i64;
+
+    fn part_1(input: &Input) -> i64 {
+        let mut sequence_results = Vec::with_capacity(input.sequences.len());
+
+        for mut sequence in input.sequences.iter().cloned() {
+            let mut end_idx = sequence.len();
+
+            while end_idx > 0 && !sequence[0..end_idx].iter().all(|x| *x == 0) {
+                for next in 1..end_idx {
+                    let prev = next - 1;
+                    sequence[prev] = sequence[next] - sequence[prev];
+                }
+                end_idx -= 1;
+            }
+
+            sequence_results.push(sequence[(end_idx - 1)..sequence.len()].iter().sum());
+        }
+
+        sequence_results.iter().sum()
+    }
+
+    fn part_2(input: &Input) -> i64 {
+        let mut sequence_results = Vec::with_capacity(input.sequences.len());
+
+        for mut sequence in input.sequences.iter().cloned() {
+            let mut end_idx = sequence.len();
+            sequence.reverse();
+
+            while end_idx > 0 && !sequence[0..end_idx].iter().all(|x| *x == 0) {
+                for next in 1..end_idx {
+                    let prev = next - 1;
+                    sequence[prev] -= sequence[next];
+                }
+                end_idx -= 1;
+            }
+
+            sequence_results.push(
+                sequence[(end_idx - 1)..sequence.len()]
+                    .iter()
+                    .fold(0, |acc, x| x - acc),
+            );
+        }
+
+        sequence_results.iter().sum()
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 9,
+    inputs: &[
+        ("day09/sample_input.txt", PrintMode::None),
+        ("day09/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day09>,
+};
+
+utils::answer_tests! {
+    Day09;
+    sample_input => "day09/sample_input.txt",
+    input => "day09/input.txt",
+}
@@ -0,0 +1,317 @@
+use utils::grid::Grid;
+use utils::vec::Vec2i;
+use utils::{Check, Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TileKind {
+    Start,
+    Empty,
+    LeftUp,
+    LeftRight,
+    LeftDown,
+    RightUp,
+    RightDown,
+    UpDown,
+}
+
+impl TileKind {
+    fn connections(&self) -> Vec<Vec2i> {
+        let mut result = Vec::new();
+        if self.has_up() {
+            result.push(Vec2i::UP);
+        }
+        if self.has_down() {
+            result.push(Vec2i::DOWN);
+        }
+        if self.has_left() {
+            result.push(Vec2i::LEFT);
+        }
+        if self.has_right() {
+            result.push(Vec2i::RIGHT);
+        }
+        result
+    }
+
+    fn is_start(&self) -> bool {
+        *self == Self::Start
+    }
+
+    fn has_up(&self) -> bool {
+        *self == Self::Start
+            || *self == Self::LeftUp
+            || *self == Self::RightUp
+            || *self == Self::UpDown
+    }
+
+    fn has_down(&self) -> bool {
+        *self == Self::Start
+            || *self == Self::LeftDown
+            || *self == Self::RightDown
+            || *self == Self::UpDown
+    }
+
+    fn has_left(&self) -> bool {
+        *self == Self::Start
+            || *self == Self::LeftUp
+            || *self == Self::LeftRight
+            || *self == Self::LeftDown
+    }
+
+    fn has_right(&self) -> bool {
+        *self == Self::Start
+            || *self == Self::RightUp
+            || *self == Self::LeftRight
+            || *self == Self::RightDown
+    }
+}
+
+impl std::fmt::Display for TileKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::Start => 'S',
+            Self::Empty => '.',
+            Self::LeftUp => '┘',
+            Self::LeftRight => '─',
+            Self::LeftDown => '┐',
+            Self::RightUp => '└',
+            Self::RightDown => '┌',
+            Self::UpDown => '│',
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl TryFrom<char> for TileKind {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(TileKind::Start),
+            '.' => Ok(TileKind::Empty),
+            'J' => Ok(TileKind::LeftUp),
+            '-' => Ok(TileKind::LeftRight),
+            '7' => Ok(TileKind::LeftDown),
+            'L' => Ok(TileKind::RightUp),
+            'F' => Ok(TileKind::RightDown),
+            '|' => Ok(TileKind::UpDown),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    grid: Grid<TileKind>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            grid: Grid::parse(s)?,
+        })
+    }
+
+    fn summary(&self) -> Option<String> {
+        let start = self.grid.find(|x| x.is_start())?;
+        Some(format!(
+            "{}x{} grid, start at row {}, column {}",
+            self.grid.height(),
+            self.grid.width(),
+            start.y(),
+            start.x()
+        ))
+    }
+
+    fn display(&self) -> Option<String> {
+        Some(self.grid.to_string())
+    }
+}
+
+/// Returns the neighbours of `start` with a pipe connecting to it.
+fn start_neighbours(grid: &Grid<TileKind>, start: Vec2i) -> Vec<Vec2i> {
+    grid.neighbours4(start)
+        .filter(|neighbour| {
+            grid[*neighbour]
+                .connections()
+                .into_iter()
+                .any(|connection_offset| *neighbour + connection_offset == start)
+        })
+        .collect()
+}
+
+fn find_loop(grid: &Grid<TileKind>) -> Vec<Vec2i> {
+    let mut path = Vec::new();
+
+    let start = grid.find(|x| *x == TileKind::Start).unwrap();
+
+    // Find start neighbours actually connecting to the start (should be 2).
+    let start_neighbours = start_neighbours(grid, start);
+    assert!(start_neighbours.len() == 2);
+
+    // Traverse neighbours until we get back to the start, counting how many steps it takes.
+    path.push(start);
+    path.push(start_neighbours[0]);
+    let mut prev_idx = 0;
+    let mut curr_idx = 1;
+    let mut curr_cell = &grid[path[curr_idx]];
+    while !curr_cell.is_start() {
+        let next_candidates: Vec<Vec2i> = curr_cell
+            .connections()
+            .into_iter()
+            .map(|x| path[curr_idx] + x)
+            .filter(|x| *x != path[prev_idx] && grid.contains(*x))
+            .collect();
+        assert!(next_candidates.len() == 1);
+        path.push(next_candidates[0]);
+        prev_idx += 1;
+        curr_idx += 1;
+        curr_cell = &grid[path[curr_idx]];
+    }
+
+    path
+}
+
+/// Traces the magnified grid, with walls as `X` and each area labelled by its number.
+fn print_grid(grid: &Grid<i64>) {
+    for row in grid.rows() {
+        let mut s = String::new();
+        for cell in row {
+            match cell {
+                -1 => s.push('X'),
+                0 => s.push('.'),
+                _ => s.push((cell % 10).to_string().chars().next().unwrap()),
+            }
+        }
+        utils::trace!("{s}");
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+    type Answer = u64;
+
+    fn part_1(input: &Input) -> u64 {
+        (find_loop(&input.grid).len() / 2) as u64
+    }
+
+    fn part_2(input: &Input) -> u64 {
+        let loop_path = find_loop(&input.grid);
+
+        // Build a larger grid. Each cell is magnified to a 3x3 cell, and walls are filled accordingly.
+        // This makes sure that the inner area is actually a single connected area.
+        let mut mega_grid = Grid::<i64>::filled(input.grid.size() * 3, 0);
+
+        let wall_code = -1;
+        for coords in loop_path.iter() {
+            let tile_kind = &input.grid[*coords];
+            let center = *coords * 3 + Vec2i::new(1, 1);
+
+            // Fill the walls based on the original shape.
+            // Note that the S cell will have walls in the shape of a cross.
+            // This is fine because it will result in a potential connection to all 4 adjacent tiles.
+            mega_grid[center] = wall_code;
+            if tile_kind.has_left() {
+                mega_grid[center + Vec2i::LEFT] = wall_code;
+            }
+            if tile_kind.has_right() {
+                mega_grid[center + Vec2i::RIGHT] = wall_code;
+            }
+            if tile_kind.has_up() {
+                mega_grid[center + Vec2i::UP] = wall_code;
+            }
+            if tile_kind.has_down() {
+                mega_grid[center + Vec2i::DOWN] = wall_code;
+            }
+        }
+
+        // Use flood fill to identify the inner and the outer area delimited by the walls.
+        let mut area_code = 1;
+        for coords in mega_grid.positions() {
+            if mega_grid[coords] != 0 {
+                continue;
+            }
+
+            let mut stack = Vec::new();
+            stack.push(coords);
+            while let Some(coords) = stack.pop() {
+                if mega_grid.get(coords) == Some(&0) {
+                    mega_grid[coords] = area_code;
+                    stack.extend(Vec2i::ORTHOGONAL.map(|offset| coords + offset));
+                }
+            }
+
+            area_code += 1;
+        }
+        if utils::tracing() {
+            print_grid(&mega_grid);
+        }
+
+        // By construction, area 1 will always be the outside area, because we start at point 0,0 which is never a wall and always on the outside.
+        // There is technically a single situation where this isn't true, in case the start is at the very top left, because by construction then the top left cell in the mega grid is a third area.
+        // But it doesn't matter for the inputs of this problem...
+        let label = 2;
+        input
+            .grid
+            .positions()
+            .filter(|coords| mega_grid[*coords * 3 + Vec2i::new(1, 1)] == label)
+            .count() as u64
+    }
+
+    fn validate(input: &Input) -> Vec<Check> {
+        let starts = input.grid.positions().filter(|x| input.grid[*x].is_start());
+        let start = match starts.collect::<Vec<_>>()[..] {
+            [start] => start,
+            ref starts => {
+                let error = Err(format!("found {} start tiles", starts.len()));
+                return vec![Check::new(&[1, 2], "there is a single start tile", error)];
+            }
+        };
+        let neighbours = start_neighbours(&input.grid, start).len();
+        vec![
+            Check::new(
+                &[1, 2],
+                "exactly two pipes connect to the start",
+                match neighbours {
+                    2 => Ok(()),
+                    _ => Err(format!("{neighbours} pipes connect to it")),
+                },
+            ),
+            // See the labelling of the areas in `part_2`.
+            Check::new(
+                &[2],
+                "the start is away from the top-left corner",
+                if start == Vec2i::ZERO {
+                    Err(String::from("it is at (0, 0)"))
+                } else {
+                    Ok(())
+                },
+            ),
+        ]
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 10,
+    inputs: &[
+        ("day10/sample_input_1.txt", PrintMode::None),
+        ("day10/sample_input_2.txt", PrintMode::None),
+        ("day10/sample_input_3.txt", PrintMode::None),
+        ("day10/sample_input_4.txt", PrintMode::None),
+        ("day10/sample_input_5.txt", PrintMode::None),
+        ("day10/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day10>,
+};
+
+utils::answer_tests! {
+    Day10;
+    sample_input_1 => "day10/sample_input_1.txt",
+    sample_input_2 => "day10/sample_input_2.txt",
+    sample_input_3 => "day10/sample_input_3.txt",
+    sample_input_4 => "day10/sample_input_4.txt",
+    sample_input_5 => "day10/sample_input_5.txt",
+    input => "day10/input.txt",
+}
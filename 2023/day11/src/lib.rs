@@ -0,0 +1,119 @@
+use utils::grid::Grid;
+use utils::vec::Vec2i;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    grid: Grid<u64>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut galaxy_idx = 0;
+        let grid = Grid::parse_with(s, |c| match c {
+            '.' => Ok(0),
+            '#' => {
+                galaxy_idx += 1;
+                Ok(galaxy_idx)
+            }
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        })?;
+        Ok(Self { grid })
+    }
+
+    fn summary(&self) -> Option<String> {
+        let galaxies = self.grid.cells().iter().filter(|x| **x != 0).count();
+        Some(format!(
+            "{}x{} grid, {galaxies} galaxies",
+            self.grid.height(),
+            self.grid.width()
+        ))
+    }
+
+    fn display(&self) -> Option<String> {
+        let grid = self.grid.map(|x| if *x == 0 { '.' } else { '#' });
+        Some(grid.to_string())
+    }
+}
+
+fn compute_distances(grid: &Grid<u64>, skip: i64) -> u64 {
+    assert!(skip > 0);
+
+    let empty_rows: Vec<i64> = grid
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.iter().all(|x| *x == 0))
+        .map(|(y, _)| y as i64)
+        .collect();
+
+    let empty_cols: Vec<i64> = grid
+        .columns()
+        .enumerate()
+        .filter_map(|(x, mut col)| col.all(|x| *x == 0).then_some(x as i64))
+        .collect();
+
+    let galaxies: Vec<Vec2i> = grid
+        .iter()
+        .filter(|(_, x)| **x != 0)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let distances = {
+        let mut ans = std::collections::HashMap::new();
+        for i in 0..galaxies.len() {
+            for j in (i + 1)..galaxies.len() {
+                let min_row = galaxies[i].y().min(galaxies[j].y());
+                let max_row = galaxies[i].y().max(galaxies[j].y());
+                let min_col = galaxies[i].x().min(galaxies[j].x());
+                let max_col = galaxies[i].x().max(galaxies[j].x());
+                let empty_rows_count = empty_rows
+                    .iter()
+                    .filter(|x| **x > min_row && **x < max_row)
+                    .count() as i64;
+                let empty_cols_count = empty_cols
+                    .iter()
+                    .filter(|x| **x > min_col && **x < max_col)
+                    .count() as i64;
+                let distance = max_row - min_row + empty_rows_count * (skip - 1) + max_col
+                    - min_col
+                    + empty_cols_count * (skip - 1);
+                let distance = distance as u64;
+                ans.insert((grid[galaxies[i]], grid[galaxies[j]]), distance);
+            }
+        }
+        ans
+    };
+
+    distances.values().sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Input;
+    type Answer = u64;
+
+    fn part_1(input: &Input) -> u64 {
+        compute_distances(&input.grid, 2)
+    }
+
+    fn part_2(input: &Input) -> u64 {
+        compute_distances(&input.grid, 1000000)
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 11,
+    inputs: &[
+        ("day11/sample_input.txt", PrintMode::None),
+        ("day11/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day11>,
+};
+
+utils::answer_tests! {
+    Day11;
+    sample_input => "day11/sample_input.txt",
+    input => "day11/input.txt",
+}
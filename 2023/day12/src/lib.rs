@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SpringStatus {
@@ -7,13 +8,15 @@ enum SpringStatus {
     Unknown,
 }
 
-impl From<char> for SpringStatus {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for SpringStatus {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '.' => Self::Operational,
-            '#' => Self::Damaged,
-            '?' => Self::Unknown,
-            _ => panic!("Invalid spring status"),
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
         }
     }
 }
@@ -101,51 +104,56 @@ impl Entry {
 }
 
 #[derive(Debug, Clone)]
-struct Input {
+pub struct Input {
     entries: Vec<Entry>,
 }
 
-impl From<std::fs::File> for Input {
-    fn from(file: std::fs::File) -> Self {
-        use std::io::{BufRead, BufReader};
-
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
         let mut entries = Vec::new();
-        for line in BufReader::new(file).lines().map(Result::unwrap) {
-            let (statuses_str, groups_str) = line.split_once(" ").unwrap();
-            let statuses = statuses_str
-                .chars()
-                .map(|x| SpringStatus::from(x))
-                .collect();
-            let groups = groups_str
-                .split(",")
-                .map(|x| x.parse::<usize>().unwrap())
-                .collect();
+        for line in utils::parse::lines(s) {
+            let (statuses_str, groups_str) = line.split_once(line.text, " ")?;
+            let statuses = line.parse_chars(statuses_str)?;
+            let groups = line.parse_separated(groups_str, ",")?;
             entries.push(Entry { statuses, groups });
         }
-        Self { entries }
+        Ok(Self { entries })
     }
 }
 
-fn part_1(input: &Input) -> usize {
-    input.entries.iter().map(|x| x.count_arrangements()).sum()
-}
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+    type Answer = usize;
 
-fn part_2(input: &Input) -> usize {
-    input
-        .entries
-        .iter()
-        .map(|x| x.expand(5).count_arrangements())
-        .sum()
+    fn part_1(input: &Input) -> usize {
+        input.entries.iter().map(|x| x.count_arrangements()).sum()
+    }
+
+    fn part_2(input: &Input) -> usize {
+        input
+            .entries
+            .iter()
+            .map(|x| x.expand(5).count_arrangements())
+            .sum()
+    }
 }
 
-fn main() {
-    use utils::PrintMode;
-    utils::run::<_, _>(
-        &[
-            ("day12/sample_input_1.txt", PrintMode::None),
-            ("day12/sample_input_2.txt", PrintMode::None),
-            ("day12/input.txt", PrintMode::None),
-        ],
-        &[part_1, part_2],
-    );
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 12,
+    inputs: &[
+        ("day12/sample_input_1.txt", PrintMode::None),
+        ("day12/sample_input_2.txt", PrintMode::None),
+        ("day12/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day12>,
+};
+
+utils::answer_tests! {
+    Day12;
+    sample_input_1 => "day12/sample_input_1.txt",
+    sample_input_2 => "day12/sample_input_2.txt",
+    input => "day12/input.txt",
 }
@@ -0,0 +1,216 @@
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CellKind {
+    Ash,
+    Rocks,
+}
+
+impl CellKind {
+    fn switch(&mut self) {
+        *self = match self {
+            Self::Ash => Self::Rocks,
+            Self::Rocks => Self::Ash,
+        };
+    }
+}
+
+impl TryFrom<char> for CellKind {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Ash),
+            '#' => Ok(Self::Rocks),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        }
+    }
+}
+
+impl From<CellKind> for char {
+    fn from(cell: CellKind) -> Self {
+        match cell {
+            CellKind::Ash => '.',
+            CellKind::Rocks => '#',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    cells: Vec<Vec<CellKind>>,
+}
+
+impl Entry {
+    fn new() -> Self {
+        Entry { cells: Vec::new() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    entries: Vec<Entry>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut entries: Vec<Entry> = Vec::new();
+        entries.push(Entry::new());
+        for line in utils::parse::lines(s) {
+            if line.text.is_empty() {
+                entries.push(Entry::new());
+            } else {
+                entries
+                    .last_mut()
+                    .unwrap()
+                    .cells
+                    .push(line.parse_chars(line.text)?);
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!("{} patterns", self.entries.len()))
+    }
+
+    fn display(&self) -> Option<String> {
+        let patterns: Vec<String> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                entry
+                    .cells
+                    .iter()
+                    .map(|row| row.iter().map(|x| char::from(*x)).collect::<String>() + "\n")
+                    .collect()
+            })
+            .collect();
+        Some(patterns.join("\n"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Col(usize),
+    Row(usize),
+}
+
+impl Axis {
+    fn points(&self) -> usize {
+        match self {
+            Self::Col(x) => *x,
+            Self::Row(x) => 100 * x,
+        }
+    }
+}
+
+fn transpose(cells: &[Vec<CellKind>]) -> Vec<Vec<CellKind>> {
+    let in_rows = cells.len();
+    let in_cols = cells[0].len();
+    let mut ans = Vec::with_capacity(in_cols);
+    for i in 0..in_cols {
+        ans.push(Vec::with_capacity(in_rows));
+        for row in cells.iter() {
+            ans.last_mut().unwrap().push(row[i]);
+        }
+    }
+    ans
+}
+
+fn is_symmetric(v: &[CellKind], axis_pos: usize) -> bool {
+    (0..axis_pos).all(|i| {
+        let mirror_idx = 2 * axis_pos - 1 - i;
+        mirror_idx >= v.len() || v[i] == v[mirror_idx]
+    })
+}
+
+fn find_vertical_symmetries(cells: &[Vec<CellKind>]) -> Vec<usize> {
+    let mut ans = Vec::new();
+    for i in 1..cells[0].len() {
+        if cells.iter().all(|x| is_symmetric(x, i)) {
+            ans.push(i);
+        }
+    }
+    ans
+}
+
+fn find_horizontal_symmetries(cells: &[Vec<CellKind>]) -> Vec<usize> {
+    find_vertical_symmetries(&transpose(cells))
+}
+
+fn find_symmetries(cells: &[Vec<CellKind>]) -> Vec<Axis> {
+    let vs = find_vertical_symmetries(cells);
+    let hs = find_horizontal_symmetries(cells);
+    let mut ans: Vec<Axis> = vs.iter().map(|x| Axis::Col(*x)).collect();
+    ans.append(&mut hs.iter().map(|x| Axis::Row(*x)).collect());
+    ans
+}
+
+fn find_symmetry(cells: &[Vec<CellKind>]) -> Axis {
+    let symmetries = find_symmetries(cells);
+    assert!(symmetries.len() == 1);
+    symmetries[0]
+}
+
+fn find_symmetry_with_smudge(cells: &[Vec<CellKind>]) -> Axis {
+    let orig_symmetry = find_symmetry(cells);
+
+    let mut cells = cells.to_vec();
+    for i in 0..cells.len() {
+        if utils::cancelled() {
+            // The answer is discarded.
+            return orig_symmetry;
+        }
+        for j in 0..cells[0].len() {
+            cells[i][j].switch();
+            let new_symmetries = find_symmetries(&cells);
+            if let Some(new_symmetry) = new_symmetries.into_iter().find(|x| *x != orig_symmetry) {
+                return new_symmetry;
+            }
+            cells[i][j].switch();
+        }
+    }
+
+    panic!("No new symmetry found!");
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn part_1(input: &Input) -> usize {
+        input
+            .entries
+            .iter()
+            .map(|x| find_symmetry(&x.cells).points())
+            .sum()
+    }
+
+    fn part_2(input: &Input) -> usize {
+        input
+            .entries
+            .iter()
+            .map(|x| find_symmetry_with_smudge(&x.cells).points())
+            .sum()
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 13,
+    inputs: &[
+        ("day13/sample_input.txt", PrintMode::None),
+        ("day13/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day13>,
+};
+
+utils::answer_tests! {
+    Day13;
+    sample_input => "day13/sample_input.txt",
+    input => "day13/input.txt",
+}
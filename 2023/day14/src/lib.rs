@@ -0,0 +1,160 @@
+use utils::grid::Grid;
+use utils::vec::Vec2i;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tilt {
+    West,
+    East,
+    North,
+    South,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Platform {
+    grid: Grid<char>,
+}
+
+impl Platform {
+    /// Surrounds the platform with cube-shaped rocks, so that each row is delimited by walls.
+    pub fn add_walls(&mut self) {
+        let offset = Vec2i::new(1, 1);
+        self.grid = Grid::from_fn(self.grid.size() + offset * 2, |pos| {
+            self.grid.get(pos - offset).copied().unwrap_or('#')
+        });
+    }
+
+    pub fn tilt(&mut self, tilt: Tilt) {
+        let transpose = tilt == Tilt::South || tilt == Tilt::North;
+        let get_val = match tilt {
+            Tilt::West | Tilt::North => |x: &char| if *x == 'O' { 0 } else { 1 },
+            Tilt::East | Tilt::South => |x: &char| if *x == 'O' { 1 } else { 0 },
+        };
+
+        if transpose {
+            self.grid = self.grid.transpose();
+        }
+
+        let cells = self.grid.cells_mut();
+        let mut walls: Vec<usize> = cells
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x == '#')
+            .map(|(idx, _)| idx)
+            .collect();
+        walls.push(cells.len());
+
+        let mut ranges = Vec::new();
+        let mut start = 0;
+        for wall_idx in walls.into_iter() {
+            if wall_idx > start {
+                ranges.push((start, wall_idx));
+            }
+            start = wall_idx + 1;
+        }
+
+        for (start, end) in ranges.into_iter() {
+            cells[start..end].sort_by_key(&get_val);
+        }
+
+        if transpose {
+            self.grid = self.grid.transpose();
+        }
+    }
+
+    pub fn cycle(&mut self) {
+        self.tilt(Tilt::North);
+        self.tilt(Tilt::West);
+        self.tilt(Tilt::South);
+        self.tilt(Tilt::East);
+    }
+
+    pub fn multicycle(&mut self, count: usize) {
+        use std::collections::HashMap;
+        let mut cache = HashMap::new();
+        let mut i = 0;
+        while i < count {
+            if let Some(idx) = cache.get(&self.grid) {
+                utils::trace!("Cache hit at {i} from {idx}");
+                let step = i - idx;
+                i += (count - i) / step * step;
+                while i < count {
+                    self.cycle();
+                    i += 1;
+                }
+            } else {
+                cache.insert(self.grid.clone(), i);
+                self.cycle();
+                i += 1;
+            }
+        }
+    }
+
+    pub fn calculate_load(&self) -> u64 {
+        self.grid
+            .iter()
+            .filter(|(_, x)| **x == 'O')
+            .map(|(pos, _)| (self.grid.size().y() - 1 - pos.y()) as u64)
+            .sum()
+    }
+}
+
+impl ParseInput for Platform {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(s, |c| match c {
+            'O' | '#' | '.' => Ok(c),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        })?;
+        let mut platform = Self { grid };
+        platform.add_walls();
+        Ok(platform)
+    }
+
+    fn summary(&self) -> Option<String> {
+        let rocks = self.grid.cells().iter().filter(|x| **x == 'O').count();
+        Some(format!(
+            "{}x{} grid (including walls), {rocks} rounded rocks",
+            self.grid.height(),
+            self.grid.width()
+        ))
+    }
+
+    fn display(&self) -> Option<String> {
+        Some(self.grid.to_string())
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+    type Answer = u64;
+
+    fn part_1(input: &Platform) -> u64 {
+        let mut platform = input.clone();
+        platform.tilt(Tilt::North);
+        platform.calculate_load()
+    }
+
+    fn part_2(input: &Platform) -> u64 {
+        let mut platform = input.clone();
+        platform.multicycle(1000000000);
+        platform.calculate_load()
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 14,
+    inputs: &[
+        ("day14/sample_input.txt", PrintMode::None),
+        ("day14/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day14>,
+};
+
+utils::answer_tests! {
+    Day14;
+    sample_input => "day14/sample_input.txt",
+    input => "day14/input.txt",
+}
@@ -0,0 +1,116 @@
+use utils::parse::Line;
+use utils::{Day, ParseError, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    steps: Vec<String>,
+    operations: Vec<Step>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut steps = Vec::new();
+        let mut operations = Vec::new();
+        for line in utils::parse::lines(s) {
+            for step in line.text.split(',') {
+                steps.push(String::from(step));
+                operations.push(Step::parse(&line, step)?);
+            }
+        }
+        Ok(Self { steps, operations })
+    }
+}
+
+fn hash(s: &str) -> usize {
+    s.chars().fold(0, |acc, x| (acc + x as usize) * 17 % 256)
+}
+
+#[derive(Debug, Clone)]
+struct Lens {
+    label: String,
+    focal_length: u8,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Set(Lens),
+    Remove(String),
+}
+
+impl Step {
+    fn parse<'a>(line: &Line<'a>, s: &'a str) -> Result<Self, ParseError> {
+        if let Some(label) = s.strip_suffix('-') {
+            Ok(Self::Remove(String::from(label)))
+        } else {
+            let (label, focal_length) = line.split_once(s, "=")?;
+            Ok(Self::Set(Lens {
+                label: String::from(label),
+                focal_length: line.parse(focal_length)?,
+            }))
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn part_1(input: &Input) -> usize {
+        input.steps.iter().map(|x| hash(x)).sum()
+    }
+
+    fn part_2(input: &Input) -> usize {
+        let mut boxes = vec![Vec::<Lens>::new(); 256];
+        for step in input.operations.iter().cloned() {
+            match step {
+                Step::Remove(label) => {
+                    let box_idx = hash(&label);
+                    if let Some(lens_idx) = boxes[box_idx].iter().position(|x| x.label == label) {
+                        boxes[box_idx].remove(lens_idx);
+                    }
+                }
+                Step::Set(lens) => {
+                    let box_idx = hash(&lens.label);
+                    if let Some(lens_idx) =
+                        boxes[box_idx].iter().position(|x| x.label == lens.label)
+                    {
+                        boxes[box_idx][lens_idx] = lens;
+                    } else {
+                        boxes[box_idx].push(lens);
+                    }
+                }
+            }
+        }
+
+        boxes
+            .iter()
+            .enumerate()
+            .map(|(box_idx, boxx)| {
+                (1 + box_idx)
+                    * boxx
+                        .iter()
+                        .enumerate()
+                        .map(|(lens_idx, lens)| (1 + lens_idx) * lens.focal_length as usize)
+                        .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 15,
+    inputs: &[
+        ("day15/sample_input.txt", PrintMode::None),
+        ("day15/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day15>,
+};
+
+utils::answer_tests! {
+    Day15;
+    sample_input => "day15/sample_input.txt",
+    input => "day15/input.txt",
+}
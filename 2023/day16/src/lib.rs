@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+use utils::grid::Grid;
+use utils::vec::Vec2i;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contraption {
+    grid: Grid<char>,
+}
+
+impl Contraption {
+    pub fn find_energized_tiles(&self, pos: Vec2i, dir: Vec2i) -> Grid<bool> {
+        let mut cache = HashSet::new();
+        let mut energized_tiles = Grid::filled(self.grid.size(), false);
+        self.project_beam(&mut cache, &mut energized_tiles, pos, dir);
+        energized_tiles
+    }
+
+    pub fn project_beam(
+        &self,
+        cache: &mut HashSet<(Vec2i, Vec2i)>,
+        energised_tiles: &mut Grid<bool>,
+        mut pos: Vec2i,
+        mut dir: Vec2i,
+    ) {
+        while let Some(tile) = self.grid.get(pos) {
+            cache.insert((pos, dir));
+            energised_tiles[pos] = true;
+            match tile {
+                '.' => {
+                    pos += dir;
+                }
+                '/' => {
+                    dir.0.swap(0, 1);
+                    dir = -dir;
+                    pos += dir;
+                }
+                '\\' => {
+                    dir.0.swap(0, 1);
+                    pos += dir;
+                }
+                '|' => {
+                    if dir.x() != 0 {
+                        for dir in [Vec2i::UP, Vec2i::DOWN] {
+                            let pos = pos + dir;
+                            if !cache.contains(&(pos, dir)) {
+                                self.project_beam(cache, energised_tiles, pos, dir)
+                            }
+                        }
+                        return;
+                    } else {
+                        pos += dir;
+                    }
+                }
+                '-' => {
+                    if dir.y() != 0 {
+                        for dir in [Vec2i::LEFT, Vec2i::RIGHT] {
+                            let pos = pos + dir;
+                            if !cache.contains(&(pos, dir)) {
+                                self.project_beam(cache, energised_tiles, pos, dir)
+                            }
+                        }
+                        return;
+                    } else {
+                        pos += dir;
+                    }
+                }
+                _ => panic!("Invalid character"),
+            }
+        }
+    }
+
+    fn count_energised_tiles(&self, pos: Vec2i, dir: Vec2i) -> usize {
+        self.find_energized_tiles(pos, dir)
+            .cells()
+            .iter()
+            .filter(|x| **x)
+            .count()
+    }
+}
+
+impl ParseInput for Contraption {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(s, |c| match c {
+            '.' | '/' | '\\' | '|' | '-' => Ok(c),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        })?;
+        Ok(Self { grid })
+    }
+
+    fn summary(&self) -> Option<String> {
+        let devices = self.grid.cells().iter().filter(|x| **x != '.').count();
+        Some(format!(
+            "{}x{} grid, {devices} mirrors and splitters",
+            self.grid.height(),
+            self.grid.width()
+        ))
+    }
+
+    fn display(&self) -> Option<String> {
+        Some(self.grid.to_string())
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Contraption;
+    type Answer = usize;
+
+    fn part_1(contraption: &Contraption) -> usize {
+        contraption.count_energised_tiles(Vec2i::ZERO, Vec2i::RIGHT)
+    }
+
+    fn part_2(contraption: &Contraption) -> usize {
+        let size = contraption.grid.size();
+        let mut starting_configs = Vec::new();
+        for y in 0..size.y() {
+            starting_configs.push((Vec2i::new(0, y), Vec2i::RIGHT));
+            starting_configs.push((Vec2i::new(size.x() - 1, y), Vec2i::LEFT));
+        }
+        for x in 0..size.x() {
+            starting_configs.push((Vec2i::new(x, 0), Vec2i::DOWN));
+            starting_configs.push((Vec2i::new(x, size.y() - 1), Vec2i::UP));
+        }
+        starting_configs
+            .into_iter()
+            .map(|(pos, dir)| contraption.count_energised_tiles(pos, dir))
+            .max()
+            .unwrap()
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 16,
+    inputs: &[
+        ("day16/sample_input.txt", PrintMode::None),
+        ("day16/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day16>,
+};
+
+utils::answer_tests! {
+    Day16;
+    sample_input => "day16/sample_input.txt",
+    input => "day16/input.txt",
+}
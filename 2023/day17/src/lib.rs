@@ -0,0 +1,402 @@
+use utils::grid::Grid;
+use utils::search;
+use utils::vec::Vec2i;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+/// Where the crucible is, the direction it moved in last and how many times in a row. The
+/// direction is zero until it first moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Crucible {
+    pos: Vec2i,
+    dir: Vec2i,
+    steps: usize,
+}
+
+/// How a crucible can move through the city.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MovementRules {
+    /// Blocks the crucible must move in a row in the same direction before it can turn or stop.
+    pub min_steps: usize,
+    /// Blocks the crucible can move at most in a row in the same direction.
+    pub max_steps: usize,
+    /// Whether the crucible can also move diagonally.
+    pub diagonal: bool,
+    /// Whether the crucible can turn around.
+    pub reversals: bool,
+    /// Heat lost whenever the crucible changes direction, on top of the heat lost in the block it
+    /// enters.
+    pub turn_penalty: u64,
+}
+
+impl MovementRules {
+    /// The crucible of part 1.
+    pub const CRUCIBLE: Self = Self {
+        min_steps: 0,
+        max_steps: 3,
+        diagonal: false,
+        reversals: false,
+        turn_penalty: 0,
+    };
+
+    /// The ultra crucible of part 2.
+    pub const ULTRA_CRUCIBLE: Self = Self {
+        min_steps: 4,
+        max_steps: 10,
+        ..Self::CRUCIBLE
+    };
+
+    fn directions(&self) -> &'static [Vec2i] {
+        if self.diagonal {
+            &Vec2i::ADJACENT
+        } else {
+            &Vec2i::ORTHOGONAL
+        }
+    }
+
+    /// The moves `crucible` can make, with the block it enters and the heat lost by turning.
+    fn moves(&self, crucible: &Crucible) -> impl Iterator<Item = (Crucible, u64)> + '_ {
+        let moving = crucible.dir != Vec2i::ZERO;
+        let crucible = *crucible;
+        self.directions()
+            .iter()
+            .filter(move |&&dir| !moving || crucible.steps >= self.min_steps || dir == crucible.dir)
+            .filter(move |&&dir| self.reversals || -dir != crucible.dir)
+            .filter_map(move |&dir| {
+                let (steps, penalty) = if dir == crucible.dir {
+                    (crucible.steps + 1, 0)
+                } else if moving {
+                    (1, self.turn_penalty)
+                } else {
+                    (1, 0)
+                };
+                let pos = crucible.pos + dir;
+                (steps <= self.max_steps).then_some((Crucible { pos, dir, steps }, penalty))
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct City {
+    grid: Grid<u64>,
+}
+
+/// The cheapest route of a crucible through the city.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route<'a> {
+    city: &'a City,
+    pub heat_loss: u64,
+    /// Where the crucible starts from.
+    pub start: Vec2i,
+    /// Each block the crucible enters, with the direction it enters it in.
+    pub steps: Vec<(Vec2i, Vec2i)>,
+}
+
+impl std::fmt::Display for Route<'_> {
+    /// Draws the route over the heat loss of the blocks, as in the puzzle statement.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut grid = self
+            .city
+            .grid
+            .map(|x| char::from_digit(*x as u32, 10).unwrap_or('?'));
+        for &(pos, dir) in &self.steps {
+            grid[pos] = match (dir.x(), dir.y()) {
+                (1, 0) => '>',
+                (-1, 0) => '<',
+                (0, -1) => '^',
+                (0, 1) => 'v',
+                (1, -1) | (-1, 1) => '/',
+                _ => '\\',
+            };
+        }
+        write!(f, "{}", grid.to_string().trim_end())
+    }
+}
+
+impl City {
+    /// Finds the route from one of `starts` to one of `goals` losing the least heat, for a crucible
+    /// moving by `rules`. Returns `None` if no goal can be reached.
+    pub fn find_route(
+        &self,
+        starts: &[Vec2i],
+        goals: &[Vec2i],
+        rules: &MovementRules,
+    ) -> Option<Route<'_>> {
+        // The search starts before the crucible is placed on any of the starts.
+        let successors = |state: &Option<Crucible>| -> Vec<(Option<Crucible>, u64)> {
+            match state {
+                None => starts
+                    .iter()
+                    .filter(|pos| self.grid.contains(**pos))
+                    .map(|&pos| {
+                        let crucible = Crucible {
+                            pos,
+                            dir: Vec2i::ZERO,
+                            steps: 0,
+                        };
+                        (Some(crucible), 0)
+                    })
+                    .collect(),
+                Some(crucible) => rules
+                    .moves(crucible)
+                    .filter_map(|(next, penalty)| {
+                        let heat_loss = self.grid.get(next.pos)?;
+                        Some((Some(next), heat_loss + penalty))
+                    })
+                    .collect(),
+            }
+        };
+        let is_goal = |state: &Option<Crucible>| {
+            state.is_some_and(|crucible| {
+                goals.contains(&crucible.pos) && crucible.steps >= rules.min_steps
+            })
+        };
+        let path = search::dijkstra(None, successors, is_goal)?;
+        let crucibles: Vec<_> = path.states.into_iter().flatten().collect();
+        Some(Route {
+            city: self,
+            heat_loss: path.cost,
+            start: crucibles[0].pos,
+            steps: crucibles[1..]
+                .iter()
+                .map(|crucible| (crucible.pos, crucible.dir))
+                .collect(),
+        })
+    }
+
+    /// The heat lost by the cheapest route from the top-left block to the bottom-right one, which
+    /// is traced.
+    fn min_heat_loss(&self, rules: &MovementRules) -> Option<u64> {
+        let goal = self.grid.size() - Vec2i::new(1, 1);
+        let route = self.find_route(&[Vec2i::ZERO], &[goal], rules)?;
+        utils::trace!("{route}");
+        Some(route.heat_loss)
+    }
+}
+
+impl ParseInput for City {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(s, |c| {
+            c.to_digit(10)
+                .map(u64::from)
+                .ok_or(ParseErrorKind::UnexpectedChar(c))
+        })?;
+        Ok(Self { grid })
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!("{}x{} grid", self.grid.height(), self.grid.width()))
+    }
+
+    fn display(&self) -> Option<String> {
+        Some(self.grid.to_string())
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = City;
+    type Answer = Option<u64>;
+
+    fn part_1(city: &City) -> Option<u64> {
+        city.min_heat_loss(&MovementRules::CRUCIBLE)
+    }
+
+    fn part_2(city: &City) -> Option<u64> {
+        city.min_heat_loss(&MovementRules::ULTRA_CRUCIBLE)
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 17,
+    inputs: &[
+        ("day17/sample_input_1.txt", PrintMode::None),
+        ("day17/sample_input_2.txt", PrintMode::None),
+        ("day17/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day17>,
+};
+
+utils::answer_tests! {
+    Day17;
+    sample_input_1 => "day17/sample_input_1.txt",
+    sample_input_2 => "day17/sample_input_2.txt",
+    input => "day17/input.txt",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn city(s: &str) -> City {
+        City::parse(s).unwrap()
+    }
+
+    /// The route of the puzzle statement for the first sample, one of the cheapest ones.
+    const SAMPLE_1_ROUTE: &str = "\
+2>>34^>>>1323
+32v>>>35v5623
+32552456v>>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>";
+
+    #[test]
+    fn routes_are_drawn_over_the_grid() {
+        let city = utils::testing::parse_input::<Day17>(2023, "day17/sample_input_1.txt");
+        let steps: Vec<_> = SAMPLE_1_ROUTE
+            .lines()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars().enumerate().filter_map(move |(x, c)| {
+                    let dir = match c {
+                        '>' => Vec2i::RIGHT,
+                        '<' => Vec2i::LEFT,
+                        '^' => Vec2i::UP,
+                        'v' => Vec2i::DOWN,
+                        _ => return None,
+                    };
+                    Some((Vec2i::new(x as i64, y as i64), dir))
+                })
+            })
+            .collect();
+        let route = Route {
+            city: &city,
+            heat_loss: steps.iter().map(|(pos, _)| city.grid[*pos]).sum(),
+            start: Vec2i::ZERO,
+            steps,
+        };
+        assert_eq!(route.heat_loss, 102);
+        assert_eq!(route.to_string(), SAMPLE_1_ROUTE);
+
+        // The route found is as cheap, but goes another way in case of ties.
+        let goal = city.grid.size() - Vec2i::new(1, 1);
+        let found = city
+            .find_route(&[Vec2i::ZERO], &[goal], &MovementRules::CRUCIBLE)
+            .unwrap();
+        assert_eq!(found.heat_loss, route.heat_loss);
+        let drawn = found.to_string();
+        assert_eq!(drawn.lines().count(), 13);
+        assert_eq!(
+            drawn.chars().filter(|c| "<>^v".contains(*c)).count(),
+            found.steps.len()
+        );
+    }
+
+    #[test]
+    fn diagonal_routes_are_drawn_with_slashes() {
+        let city = city("111\n111\n111");
+        let rules = MovementRules {
+            diagonal: true,
+            ..MovementRules::CRUCIBLE
+        };
+        let corners = [Vec2i::new(0, 0), Vec2i::new(2, 2)];
+        let route = city.find_route(&corners[..1], &corners[1..], &rules);
+        assert_eq!(route.unwrap().to_string(), "111\n1\\1\n11\\");
+        let route = city.find_route(&corners[1..], &corners[..1], &rules);
+        assert_eq!(route.unwrap().to_string(), "\\11\n1\\1\n111");
+
+        let corners = [Vec2i::new(0, 2), Vec2i::new(2, 0)];
+        let route = city.find_route(&corners[..1], &corners[1..], &rules);
+        assert_eq!(route.unwrap().to_string(), "11/\n1/1\n111");
+    }
+
+    #[test]
+    fn diagonal_moves_can_be_shorter() {
+        let city = city("19\n91");
+        let goal = Vec2i::new(1, 1);
+        let route = city.find_route(&[Vec2i::ZERO], &[goal], &MovementRules::CRUCIBLE);
+        assert_eq!(route.unwrap().heat_loss, 10);
+
+        let rules = MovementRules {
+            diagonal: true,
+            ..MovementRules::CRUCIBLE
+        };
+        let route = city.find_route(&[Vec2i::ZERO], &[goal], &rules).unwrap();
+        assert_eq!(route.heat_loss, 1);
+        assert_eq!(route.steps, [(goal, Vec2i::new(1, 1))]);
+    }
+
+    #[test]
+    fn turn_penalties_favour_straighter_routes() {
+        let city = city("11119\n81111");
+        let goal = Vec2i::new(4, 1);
+        let rules = MovementRules {
+            max_steps: 10,
+            ..MovementRules::CRUCIBLE
+        };
+        // Through the top row, turning twice.
+        let route = city.find_route(&[Vec2i::ZERO], &[goal], &rules).unwrap();
+        assert_eq!(route.heat_loss, 5);
+
+        let rules = MovementRules {
+            turn_penalty: 10,
+            ..rules
+        };
+        let route = city.find_route(&[Vec2i::ZERO], &[goal], &rules).unwrap();
+        assert_eq!(route.heat_loss, 8 + 4 + 10);
+        let dirs: Vec<_> = route.steps.iter().map(|(_, dir)| *dir).collect();
+        assert_eq!(
+            dirs,
+            [
+                Vec2i::DOWN,
+                Vec2i::RIGHT,
+                Vec2i::RIGHT,
+                Vec2i::RIGHT,
+                Vec2i::RIGHT
+            ]
+        );
+    }
+
+    #[test]
+    fn reversals_can_be_allowed() {
+        // The crucible must move 3 blocks before turning, so it can only stop next to the start
+        // by going past it and coming back.
+        let city = city("1111111");
+        let goal = Vec2i::new(1, 0);
+        let rules = MovementRules {
+            min_steps: 3,
+            max_steps: 10,
+            ..MovementRules::CRUCIBLE
+        };
+        assert_eq!(city.find_route(&[Vec2i::ZERO], &[goal], &rules), None);
+
+        let rules = MovementRules {
+            reversals: true,
+            ..rules
+        };
+        let route = city.find_route(&[Vec2i::ZERO], &[goal], &rules).unwrap();
+        assert_eq!(route.heat_loss, 4 + 3);
+        assert_eq!(route.steps[3], (Vec2i::new(4, 0), Vec2i::RIGHT));
+        assert_eq!(route.steps[4], (Vec2i::new(3, 0), Vec2i::LEFT));
+    }
+
+    #[test]
+    fn the_cheapest_start_and_goal_are_chosen() {
+        let city = city("1911\n1119\n9111");
+        let starts = [Vec2i::new(0, 0), Vec2i::new(3, 0), Vec2i::new(-1, 0)];
+        let goals = [Vec2i::new(0, 2), Vec2i::new(3, 2)];
+        let route = city
+            .find_route(&starts, &goals, &MovementRules::CRUCIBLE)
+            .unwrap();
+        // From the top-right corner, (3, 1) costs 9 so the route goes around it.
+        assert_eq!(route.start, Vec2i::new(3, 0));
+        assert_eq!(route.heat_loss, 4);
+        assert_eq!(route.steps.last().unwrap().0, Vec2i::new(3, 2));
+
+        let route = city
+            .find_route(&starts[..1], &goals, &MovementRules::CRUCIBLE)
+            .unwrap();
+        // The bottom-right goal is cheaper to reach than the bottom-left one, below a 9.
+        assert_eq!((route.start, route.heat_loss), (Vec2i::ZERO, 5));
+        assert_eq!(route.steps.last().unwrap().0, Vec2i::new(3, 2));
+    }
+}
@@ -0,0 +1,186 @@
+use utils::parse::Line;
+use utils::vec::Vec2i;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Polygon(Vec<Vec2i>);
+
+impl Polygon {
+    fn get(&self, idx: usize) -> &Vec2i {
+        &self.0[idx % self.0.len()]
+    }
+
+    fn area(&self) -> i64 {
+        let mut double_area = 0;
+        for i in 0..self.0.len() {
+            double_area += self.get(i).cross(*self.get(i + 1));
+        }
+        double_area.abs() / 2
+    }
+}
+
+impl From<Vec<Instruction>> for Polygon {
+    fn from(instructions: Vec<Instruction>) -> Self {
+        let mut poly = Polygon(Vec::with_capacity(instructions.len() + 1));
+        poly.0.push(Vec2i::ZERO);
+        instructions
+            .into_iter()
+            .for_each(|Instruction { dir, len }| {
+                poly.0
+                    .push(*poly.0.last().unwrap() + len * Vec2i::from(dir));
+            });
+        poly
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Left,
+    Right,
+    Down,
+    Up,
+}
+
+impl From<Direction> for Vec2i {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Down => Vec2i::new(0, -1),
+            Direction::Up => Vec2i::new(0, 1),
+            Direction::Left => Vec2i::new(-1, 0),
+            Direction::Right => Vec2i::new(1, 0),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            'U' => Ok(Self::Up),
+            'D' => Ok(Self::Down),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Instruction {
+    dir: Direction,
+    len: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    instruction: Instruction,
+    color_instruction: Instruction,
+}
+
+impl Entry {
+    fn parse(line: &Line) -> Result<Self, ParseError> {
+        let (dir, len) = line.split_once(line.text, " ")?;
+        let (len, col) = line.split_once(len, " ")?;
+
+        let instruction = {
+            let dir = match line.parse_chars::<Direction>(dir)?[..] {
+                [dir] => dir,
+                _ => return Err(line.error(dir, ParseErrorKind::InvalidValue(String::from(dir)))),
+            };
+            let len = line.parse(len)?;
+            Instruction { dir, len }
+        };
+
+        let color_instruction = {
+            let col = line.strip_prefix(col, "(#")?;
+            let col = line.strip_suffix(col, ")")?;
+            if col.len() != 6 || !col.is_ascii() {
+                return Err(line.error(col, ParseErrorKind::InvalidValue(String::from(col))));
+            }
+            let (len, dir) = col.split_at(5);
+            let dir = match dir {
+                "0" => Direction::Right,
+                "1" => Direction::Down,
+                "2" => Direction::Left,
+                "3" => Direction::Up,
+                _ => return Err(line.error(dir, ParseErrorKind::InvalidValue(String::from(dir)))),
+            };
+            let len = i64::from_str_radix(len, 16)
+                .map_err(|_| line.error(len, ParseErrorKind::InvalidValue(String::from(len))))?;
+            Instruction { dir, len }
+        };
+
+        Ok(Self {
+            instruction,
+            color_instruction,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    entries: Vec<Entry>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let entries = utils::parse::lines(s)
+            .map(|line| Entry::parse(&line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Input;
+    type Answer = i64;
+
+    fn part_1(input: &Input) -> i64 {
+        let instructions: Vec<Instruction> = input
+            .entries
+            .iter()
+            .map(|x| x.instruction.clone())
+            .collect();
+        let perimeter: i64 = instructions
+            .iter()
+            .map(|Instruction { dir: _, len }| len)
+            .sum();
+        let area = Polygon::from(instructions).area();
+        area + perimeter / 2 + 1
+    }
+
+    fn part_2(input: &Input) -> i64 {
+        let instructions: Vec<Instruction> = input
+            .entries
+            .iter()
+            .map(|x| x.color_instruction.clone())
+            .collect();
+        let perimeter: i64 = instructions
+            .iter()
+            .map(|Instruction { dir: _, len }| len)
+            .sum();
+        let area = Polygon::from(instructions).area();
+        area + perimeter / 2 + 1
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 18,
+    inputs: &[
+        ("day18/sample_input.txt", PrintMode::None),
+        ("day18/custom_input_1.txt", PrintMode::None),
+        ("day18/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day18>,
+};
+
+utils::answer_tests! {
+    Day18;
+    sample_input => "day18/sample_input.txt",
+    custom_input_1 => "day18/custom_input_1.txt",
+    input => "day18/input.txt",
+}
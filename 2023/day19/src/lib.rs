@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+use utils::interval::IntervalSet;
+use utils::parse::Line;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Part {
+    properties: HashMap<char, u64>,
+}
+
+impl Part {
+    fn total_rating(&self) -> u64 {
+        self.properties.values().sum()
+    }
+
+    fn is_accepted(&self, worflows: &HashMap<String, Vec<Rule>>) -> bool {
+        let mut curr_workflow = "in";
+        loop {
+            let outcome = &worflows
+                .get(curr_workflow)
+                .unwrap()
+                .iter()
+                .find(|rule| rule.matches(self))
+                .unwrap()
+                .outcome;
+            match outcome {
+                Outcome::Accept => return true,
+                Outcome::Refuse => return false,
+                Outcome::GoTo(workflow) => {
+                    curr_workflow = workflow;
+                }
+            }
+        }
+    }
+
+    fn parse(line: &Line) -> Result<Self, ParseError> {
+        let s = line.strip_prefix(line.text, "{")?;
+        let s = line.strip_suffix(s, "}")?;
+        let mut properties = HashMap::new();
+        for x in s.split(',') {
+            let (name, val) = line.split_once(x, "=")?;
+            let property = parse_property(line, name)?;
+            if properties.insert(property, line.parse(val)?).is_some() {
+                let message = format!("'{property}' is rated twice");
+                return Err(line.error(name, ParseErrorKind::Other(message)));
+            }
+        }
+        if let Some(property) = "xmas".chars().find(|x| !properties.contains_key(x)) {
+            let message = format!("'{property}' is not rated");
+            return Err(line.error_after(s, ParseErrorKind::Other(message)));
+        }
+        Ok(Self { properties })
+    }
+}
+
+fn parse_property<'a>(line: &Line<'a>, s: &'a str) -> Result<char, ParseError> {
+    match s {
+        "x" | "m" | "a" | "s" => Ok(s.chars().next().unwrap()),
+        _ => Err(line.error(s, ParseErrorKind::InvalidValue(String::from(s)))),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    GoTo(String),
+    Accept,
+    Refuse,
+}
+
+impl From<&str> for Outcome {
+    fn from(s: &str) -> Self {
+        match s {
+            "A" => Outcome::Accept,
+            "R" => Outcome::Refuse,
+            _ => Outcome::GoTo(String::from(s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Comparison {
+    property: char,
+    ordering: std::cmp::Ordering,
+    value: u64,
+}
+
+impl Comparison {
+    /// The ratings for which the comparison holds.
+    fn range(&self) -> IntervalSet<u64> {
+        match self.ordering {
+            std::cmp::Ordering::Less => {
+                IntervalSet::from(RATINGS.start..self.value.min(RATINGS.end))
+            }
+            _ => IntervalSet::from(self.value.saturating_add(1).max(RATINGS.start)..RATINGS.end),
+        }
+    }
+
+    fn matches(&self, part: &Part) -> bool {
+        part.properties
+            .get(&self.property)
+            .unwrap()
+            .cmp(&self.value)
+            == self.ordering
+    }
+
+    fn parse<'a>(line: &Line<'a>, s: &'a str) -> Result<Self, ParseError> {
+        let operator_idx = s.find(['<', '>']).ok_or_else(|| {
+            line.error_after(
+                s,
+                ParseErrorKind::Other(String::from("expected '<' or '>'")),
+            )
+        })?;
+        let property = parse_property(line, &s[..operator_idx])?;
+        let ordering = match &s[operator_idx..operator_idx + 1] {
+            "<" => std::cmp::Ordering::Less,
+            _ => std::cmp::Ordering::Greater,
+        };
+        let value = line.parse(&s[operator_idx + 1..])?;
+
+        Ok(Self {
+            property,
+            ordering,
+            value,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    comparison: Option<Comparison>,
+    outcome: Outcome,
+}
+
+impl Rule {
+    fn matches(&self, part: &Part) -> bool {
+        match &self.comparison {
+            Some(comparison) => comparison.matches(part),
+            None => true,
+        }
+    }
+
+    fn parse<'a>(line: &Line<'a>, s: &'a str) -> Result<Self, ParseError> {
+        match s.split_once(':') {
+            Some((comparison, outcome)) => Ok(Self {
+                comparison: Some(Comparison::parse(line, comparison)?),
+                outcome: Outcome::from(outcome),
+            }),
+            None => Ok(Self {
+                comparison: None,
+                outcome: Outcome::from(s),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    workflows: HashMap<String, Vec<Rule>>,
+    parts: Vec<Part>,
+}
+
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut lines = utils::parse::lines(s);
+
+        let mut workflows = HashMap::new();
+        for line in lines.by_ref() {
+            if line.text.is_empty() {
+                break;
+            }
+            let (worflow_name, rules) = line.split_once(line.text, "{")?;
+            let rules = line.strip_suffix(rules, "}")?;
+            let workflow_rules = rules
+                .split(',')
+                .map(|x| Rule::parse(&line, x))
+                .collect::<Result<_, _>>()?;
+            workflows.insert(String::from(worflow_name), workflow_rules);
+        }
+
+        let mut parts = Vec::new();
+        for line in lines {
+            parts.push(Part::parse(&line)?);
+        }
+
+        Ok(Self { workflows, parts })
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!(
+            "{} workflows, {} parts",
+            self.workflows.len(),
+            self.parts.len()
+        ))
+    }
+}
+
+const PROPERTIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+/// The values a rating can have, whatever the property.
+const RATINGS: std::ops::Range<u64> = 1..4001;
+
+/// The ratings of each property that parts can have.
+type Ratings = [IntervalSet<u64>; 4];
+
+fn count_combinations(ratings: &Ratings) -> u64 {
+    ratings.iter().map(|x| x.len()).product()
+}
+
+/// Counts the parts with `ratings` accepted when starting from `workflow`, by splitting the
+/// ratings between the rules they match.
+fn count_accepted(workflows: &HashMap<String, Vec<Rule>>, workflow: &str, ratings: Ratings) -> u64 {
+    let mut rest = ratings;
+    let mut count = 0;
+    for rule in workflows.get(workflow).unwrap() {
+        let matching = match &rule.comparison {
+            Some(comparison) => {
+                let idx = PROPERTIES
+                    .iter()
+                    .position(|x| *x == comparison.property)
+                    .unwrap();
+                let range = comparison.range();
+                let mut matching = rest.clone();
+                matching[idx] = rest[idx].intersection(&range);
+                rest[idx] = rest[idx].difference(&range);
+                matching
+            }
+            None => std::mem::take(&mut rest),
+        };
+        if matching.iter().any(|x| x.is_empty()) {
+            continue;
+        }
+        count += match &rule.outcome {
+            Outcome::Accept => count_combinations(&matching),
+            Outcome::Refuse => 0,
+            Outcome::GoTo(next) => count_accepted(workflows, next, matching),
+        };
+    }
+    count
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Input;
+    type Answer = u64;
+
+    fn part_1(input: &Input) -> u64 {
+        input
+            .parts
+            .iter()
+            .filter(|part| part.is_accepted(&input.workflows))
+            .map(|part| part.total_rating())
+            .sum()
+    }
+
+    fn part_2(input: &Input) -> u64 {
+        let ratings = std::array::from_fn(|_| IntervalSet::from(RATINGS));
+        count_accepted(&input.workflows, "in", ratings)
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 19,
+    inputs: &[
+        ("day19/sample_input.txt", PrintMode::None),
+        ("day19/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day19>,
+};
+
+utils::answer_tests! {
+    Day19;
+    sample_input => "day19/sample_input.txt",
+    input => "day19/input.txt",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts_must_rate_each_property_once() {
+        let error = |part: &str| {
+            let error = Input::parse(&format!("in{{A}}\n\n{part}")).unwrap_err();
+            (error.line, error.column, error.kind.to_string())
+        };
+        assert_eq!(
+            error("{x=1,m=2,a=3}"),
+            (3, 13, String::from("'s' is not rated"))
+        );
+        assert_eq!(
+            error("{x=1,m=2,x=3,a=4,s=5}"),
+            (3, 10, String::from("'x' is rated twice"))
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Pulse {
@@ -114,33 +115,29 @@ impl Machine {
 }
 
 #[derive(Debug, Clone)]
-struct Input {
+pub struct Input {
     machine: Machine,
 }
 
-impl From<std::fs::File> for Input {
-    fn from(file: std::fs::File) -> Self {
-        use std::io::{BufRead, BufReader};
+impl ParseInput for Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
         let mut modules = HashMap::new();
         let mut connections = HashMap::new();
-        for line in BufReader::new(file).lines().map(Result::unwrap) {
-            let (module_type, outputs) = line.split_once(" -> ").unwrap();
+        for line in utils::parse::lines(s) {
+            let (module_type, outputs) = line.split_once(line.text, " -> ")?;
 
             let (module_name, operation) = {
                 if module_type == "broadcaster" {
                     (String::from(module_type), Operation::Broadcaster)
-                } else if module_type.starts_with("%") {
-                    (
-                        String::from(&module_type[1..module_type.len()]),
-                        Operation::FlipFlop(Pulse::Low),
-                    )
-                } else if module_type.starts_with("&") {
-                    (
-                        String::from(&module_type[1..module_type.len()]),
-                        Operation::Conjunction(HashMap::new()),
-                    )
+                } else if let Some(name) = module_type.strip_prefix('%') {
+                    (String::from(name), Operation::FlipFlop(Pulse::Low))
+                } else if let Some(name) = module_type.strip_prefix('&') {
+                    (String::from(name), Operation::Conjunction(HashMap::new()))
                 } else {
-                    panic!("Invalid module")
+                    return Err(line.error(
+                        module_type,
+                        ParseErrorKind::InvalidValue(String::from(module_type)),
+                    ));
                 }
             };
 
@@ -173,75 +170,92 @@ impl From<std::fs::File> for Input {
             }
         }
 
-        Self {
+        Ok(Self {
             machine: Machine {
                 modules,
                 low_pulses: 0,
                 high_pulses: 0,
                 button_presses: 0,
             },
-        }
+        })
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!("{} modules", self.machine.modules.len()))
     }
 }
 
-fn part_1(input: &Input) -> u64 {
-    let max_button_presses = 1000;
-    let mut machine = input.machine.clone();
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Input;
+    type Answer = u64;
 
-    while machine.button_presses < max_button_presses {
-        machine.push_button();
-        if machine.all_lows() {
-            break;
+    fn part_1(input: &Input) -> u64 {
+        let max_button_presses = 1000;
+        let mut machine = input.machine.clone();
+
+        while machine.button_presses < max_button_presses {
+            machine.push_button();
+            if machine.all_lows() {
+                break;
+            }
         }
-    }
 
-    let loop_repeats = max_button_presses / machine.button_presses;
-    let loop_low_pulses = machine.low_pulses * loop_repeats;
-    let loop_high_pulses = machine.high_pulses * loop_repeats;
+        let loop_repeats = max_button_presses / machine.button_presses;
+        let loop_low_pulses = machine.low_pulses * loop_repeats;
+        let loop_high_pulses = machine.high_pulses * loop_repeats;
 
-    machine.low_pulses = 0;
-    machine.high_pulses = 0;
-    for _ in 0..max_button_presses % machine.button_presses {
-        machine.push_button();
+        machine.low_pulses = 0;
+        machine.high_pulses = 0;
+        for _ in 0..max_button_presses % machine.button_presses {
+            machine.push_button();
+        }
+
+        (loop_low_pulses + machine.low_pulses) * (loop_high_pulses + machine.high_pulses)
     }
 
-    (loop_low_pulses + machine.low_pulses) * (loop_high_pulses + machine.high_pulses)
-}
+    fn part_2(_input: &Input) -> u64 {
+        0
+        // let mut machine = input.machine.clone();
 
-fn part_2(_input: &Input) -> u64 {
-    0
-    // let mut machine = input.machine.clone();
+        // let rx_inputs = machine.find_node_inputs("rx");
+        // if rx_inputs.len() != 1 {
+        //     return 0;
+        // }
 
-    // let rx_inputs = machine.find_node_inputs("rx");
-    // if rx_inputs.len() != 1 {
-    //     return 0;
-    // }
+        // let rx_inputs = machine.find_node_inputs(&rx_inputs[0]);
+        // let mut cycles = vec![None; rx_inputs.len()];
 
-    // let rx_inputs = machine.find_node_inputs(&rx_inputs[0]);
-    // let mut cycles = vec![None; rx_inputs.len()];
-
-    // while cycles.iter().any(Option::is_none) {
-    //     machine.push_button();
-    //     for (i, input) in rx_inputs.iter().enumerate() {
-    //         if let Some(pulse) = machine.modules.get(input).unwrap().operation.state() {
-    //             if pulse == Pulse::High {
-    //                 cycles[i] = Some(machine.button_presses);
-    //             }
-    //         }
-    //     }
-    // }
+        // while cycles.iter().any(Option::is_none) {
+        //     machine.push_button();
+        //     for (i, input) in rx_inputs.iter().enumerate() {
+        //         if let Some(pulse) = machine.modules.get(input).unwrap().operation.state() {
+        //             if pulse == Pulse::High {
+        //                 cycles[i] = Some(machine.button_presses);
+        //             }
+        //         }
+        //     }
+        // }
 
-    // cycles.iter().map(|x| x.unwrap()).fold(1, |acc, x| acc * x)
+        // cycles.iter().map(|x| x.unwrap()).fold(1, |acc, x| acc * x)
+    }
 }
 
-fn main() {
-    use utils::PrintMode;
-    utils::run::<_, _>(
-        &[
-            ("day20/sample_input_1.txt", PrintMode::None),
-            ("day20/sample_input_2.txt", PrintMode::None),
-            ("day20/input.txt", PrintMode::None),
-        ],
-        &[part_1, part_2],
-    );
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 20,
+    inputs: &[
+        ("day20/sample_input_1.txt", PrintMode::None),
+        ("day20/sample_input_2.txt", PrintMode::None),
+        ("day20/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day20>,
+};
+
+utils::answer_tests! {
+    Day20;
+    sample_input_1 => "day20/sample_input_1.txt",
+    sample_input_2 => "day20/sample_input_2.txt",
+    input => "day20/input.txt",
 }
@@ -0,0 +1,151 @@
+use utils::grid::Grid;
+use utils::search;
+use utils::vec::Vec2i;
+use utils::{Check, Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Garden {
+    grid: Grid<char>,
+    start: Vec2i,
+}
+
+impl Garden {
+    pub fn calc_distances(&self, max_distance: u64) -> Grid<u64> {
+        let reached = search::bfs_within(self.start, max_distance as usize, |pos| {
+            self.grid
+                .neighbours4(*pos)
+                .filter(|next_pos| self.grid[*next_pos] != '#')
+        });
+
+        let mut distances = Grid::filled(self.grid.size(), u64::MAX);
+        for (pos, distance) in reached {
+            distances[pos] = distance as u64;
+        }
+        distances
+    }
+
+    pub fn count_cells_reachable_by_steps(&self, steps: u64) -> usize {
+        self.calc_distances(steps)
+            .cells()
+            .iter()
+            .filter(|dist| **dist < u64::MAX && **dist % 2 == steps % 2)
+            .count()
+    }
+}
+
+impl ParseInput for Garden {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(s, |c| match c {
+            '.' | '#' | 'S' => Ok(c),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        })?;
+        let starts: Vec<Vec2i> = grid
+            .iter()
+            .filter(|(_, c)| **c == 'S')
+            .map(|(pos, _)| pos)
+            .collect();
+        let start = match starts[..] {
+            [start] => start,
+            [] => {
+                let kind = ParseErrorKind::Other(String::from("there is no start"));
+                return Err(ParseError::new(1, 1, kind));
+            }
+            [_, second, ..] => {
+                let kind = ParseErrorKind::Other(String::from("there is a second start"));
+                return Err(ParseError::new(
+                    second.y() as usize + 1,
+                    second.x() as usize + 1,
+                    kind,
+                ));
+            }
+        };
+        Ok(Self { grid, start })
+    }
+
+    fn summary(&self) -> Option<String> {
+        let plots = self.grid.cells().iter().filter(|x| **x != '#').count();
+        Some(format!(
+            "{}x{} grid, {plots} garden plots",
+            self.grid.height(),
+            self.grid.width()
+        ))
+    }
+
+    fn display(&self) -> Option<String> {
+        Some(self.grid.to_string())
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+    type Answer = Vec<usize>;
+
+    fn part_1(garden: &Garden) -> Vec<usize> {
+        [6, 64]
+            .iter()
+            .map(|x| garden.count_cells_reachable_by_steps(*x))
+            .collect()
+    }
+
+    fn part_2(_garden: &Garden) -> Vec<usize> {
+        Vec::new()
+    }
+
+    fn validate(garden: &Garden) -> Vec<Check> {
+        let (grid, start) = (&garden.grid, garden.start);
+        let row_rocks = (0..grid.width() as i64)
+            .filter(|x| grid[Vec2i::new(*x, start.y())] == '#')
+            .count();
+        let column_rocks = (0..grid.height() as i64)
+            .filter(|y| grid[Vec2i::new(start.x(), *y)] == '#')
+            .count();
+        // The infinite garden is then reached through straight lines from copies of the start.
+        vec![Check::new(
+            &[2],
+            "the row and the column of the start have no rocks",
+            match (row_rocks, column_rocks) {
+                (0, 0) => Ok(()),
+                _ => Err(format!(
+                    "{row_rocks} rocks on its row and {column_rocks} on its column"
+                )),
+            },
+        )]
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 21,
+    inputs: &[
+        ("day21/sample_input.txt", PrintMode::None),
+        ("day21/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day21>,
+};
+
+utils::answer_tests! {
+    Day21;
+    sample_input => "day21/sample_input.txt",
+    input => "day21/input.txt",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gardens_have_a_single_start() {
+        let error = |s: &str| {
+            let error = Garden::parse(s).unwrap_err();
+            (error.line, error.column, error.kind.to_string())
+        };
+        assert_eq!(error("...\n.#."), (1, 1, String::from("there is no start")));
+        assert_eq!(
+            error(".S.\n.#S"),
+            (2, 3, String::from("there is a second start"))
+        );
+        assert_eq!(Garden::parse(".S.\n.#.").unwrap().start, Vec2i::new(1, 0));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() -> std::process::ExitCode {
+    y2023_day21::DAY.run_all().into()
+}
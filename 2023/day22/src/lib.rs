@@ -1,82 +1,6 @@
 use std::collections::HashSet;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Vec3i {
-    x: i64,
-    y: i64,
-    z: i64,
-}
-
-impl Vec3i {
-    fn new(x: i64, y: i64, z: i64) -> Self {
-        Self { x, y, z }
-    }
-}
-
-impl std::ops::AddAssign for Vec3i {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
-    }
-}
-
-impl std::ops::Add for Vec3i {
-    type Output = Self;
-    fn add(mut self, rhs: Self) -> Self::Output {
-        self += rhs;
-        self
-    }
-}
-
-impl std::ops::SubAssign for Vec3i {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
-    }
-}
-
-impl std::ops::Sub for Vec3i {
-    type Output = Self;
-    fn sub(mut self, rhs: Self) -> Self::Output {
-        self -= rhs;
-        self
-    }
-}
-
-impl std::ops::MulAssign<i64> for Vec3i {
-    fn mul_assign(&mut self, rhs: i64) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
-    }
-}
-
-impl std::ops::Mul<i64> for Vec3i {
-    type Output = Self;
-    fn mul(mut self, rhs: i64) -> Self::Output {
-        self *= rhs;
-        self
-    }
-}
-
-impl std::ops::Mul<Vec3i> for i64 {
-    type Output = Vec3i;
-    fn mul(self, rhs: Vec3i) -> Self::Output {
-        rhs * self
-    }
-}
-
-impl From<&str> for Vec3i {
-    fn from(s: &str) -> Self {
-        let coords: Vec<i64> = s
-            .split(",")
-            .map(|x| i64::from_str_radix(x, 10).unwrap())
-            .collect();
-        Vec3i::new(coords[0], coords[1], coords[2])
-    }
-}
+use utils::vec::Vec3i;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Brick {
@@ -86,47 +10,44 @@ struct Brick {
 
 impl Brick {
     fn new(min: Vec3i, max: Vec3i) -> Self {
-        assert!(max.x >= min.x);
-        assert!(max.y >= min.y);
-        assert!(max.z >= min.z);
+        assert_eq!(min.min(max), min);
         Self { min, max }
     }
 
     fn overlaps_xy(&self, rhs: &Brick) -> bool {
-        self.max.x >= rhs.min.x
-            && rhs.max.x >= self.min.x
-            && self.max.y >= rhs.min.y
-            && rhs.max.y >= self.min.y
+        self.max.x() >= rhs.min.x()
+            && rhs.max.x() >= self.min.x()
+            && self.max.y() >= rhs.min.y()
+            && rhs.max.y() >= self.min.y()
     }
 
     fn set_z(&mut self, z: i64) {
-        let height = self.max.z - self.min.z;
-        self.min.z = z;
-        self.max.z = self.min.z + height;
+        self.max[2] += z - self.min[2];
+        self.min[2] = z;
     }
 }
 
 #[derive(Debug, Clone)]
-struct Grid {
+pub struct Grid {
     // Assumed sorted on from lower to higher min z coordinate.
     bricks: Vec<Brick>,
 }
 
 impl Grid {
     fn sort(&mut self) {
-        self.bricks.sort_by(|lhs, rhs| lhs.min.z.cmp(&rhs.min.z));
+        self.bricks.sort_by_key(|lhs| lhs.min.z());
     }
 
     fn collapse(&mut self) {
         self.sort();
         for idx_0 in 0..self.bricks.len() {
-            let orig_z = self.bricks[idx_0].min.z;
+            let orig_z = self.bricks[idx_0].min.z();
             self.bricks[idx_0].set_z(1);
             for idx_1 in 1..idx_0 + 1 {
                 let idx_1 = idx_0 - idx_1;
-                let z = self.bricks[idx_1].max.z;
+                let z = self.bricks[idx_1].max.z();
                 if z < orig_z
-                    && z >= self.bricks[idx_0].min.z
+                    && z >= self.bricks[idx_0].min.z()
                     && self.bricks[idx_0].overlaps_xy(&self.bricks[idx_1])
                 {
                     self.bricks[idx_0].set_z(z + 1);
@@ -142,7 +63,7 @@ impl Grid {
         for idx_1 in 1..idx_0 + 1 {
             let idx_1 = idx_0 - idx_1;
             let other_brick = &self.bricks[idx_1];
-            if other_brick.max.z + 1 == brick.min.z && other_brick.overlaps_xy(brick) {
+            if other_brick.max.z() + 1 == brick.min.z() && other_brick.overlaps_xy(brick) {
                 ans.push(idx_1);
             }
         }
@@ -154,7 +75,7 @@ impl Grid {
         let brick = &self.bricks[idx_0];
         for idx_1 in idx_0 + 1..self.bricks.len() {
             let other_brick = &self.bricks[idx_1];
-            if brick.max.z + 1 == other_brick.min.z && other_brick.overlaps_xy(brick) {
+            if brick.max.z() + 1 == other_brick.min.z() && other_brick.overlaps_xy(brick) {
                 ans.push(idx_1);
             }
         }
@@ -174,7 +95,7 @@ impl Grid {
 
     fn count_destroyable_bricks(&self) -> usize {
         let required_bricks = self.find_required_bricks();
-        required_bricks.iter().filter(|x| **x == false).count()
+        required_bricks.iter().filter(|x| !**x).count()
     }
 
     fn count_chain_reactions(&self) -> usize {
@@ -182,7 +103,7 @@ impl Grid {
             .find_required_bricks()
             .iter()
             .enumerate()
-            .filter(|(_, x)| **x == true)
+            .filter(|(_, x)| **x)
             .map(|(idx, _)| idx)
             .collect();
 
@@ -223,42 +144,63 @@ impl Grid {
     }
 }
 
-impl From<std::fs::File> for Grid {
-    fn from(file: std::fs::File) -> Self {
-        use std::io::{BufRead, BufReader};
-        let bricks: Vec<Brick> = BufReader::new(file)
-            .lines()
-            .map(Result::unwrap)
+impl ParseInput for Grid {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let bricks: Vec<Brick> = utils::parse::lines(s)
             .map(|line| {
-                let (min, max) = line.split_once("~").unwrap();
-                let min = Vec3i::from(min);
-                let max = Vec3i::from(max);
-                Brick::new(min, max)
+                let (min_str, max_str) = line.split_once(line.text, "~")?;
+                let min: Vec3i = min_str.parse().map_err(|kind| line.error(min_str, kind))?;
+                let max: Vec3i = max_str.parse().map_err(|kind| line.error(max_str, kind))?;
+                if min.min(max) != min {
+                    return Err(line.error(
+                        max_str,
+                        ParseErrorKind::Other(String::from(
+                            "the end of a brick must not be lower than its start",
+                        )),
+                    ));
+                }
+                Ok(Brick::new(min, max))
             })
-            .collect();
-        Self { bricks }
+            .collect::<Result<_, _>>()?;
+        Ok(Self { bricks })
     }
-}
 
-fn part_1(grid: &Grid) -> usize {
-    let mut grid = grid.clone();
-    grid.collapse();
-    grid.count_destroyable_bricks()
+    fn summary(&self) -> Option<String> {
+        Some(format!("{} bricks", self.bricks.len()))
+    }
 }
 
-fn part_2(grid: &Grid) -> usize {
-    let mut grid = grid.clone();
-    grid.collapse();
-    grid.count_chain_reactions()
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Grid;
+    type Answer = usize;
+
+    fn part_1(grid: &Grid) -> usize {
+        let mut grid = grid.clone();
+        grid.collapse();
+        grid.count_destroyable_bricks()
+    }
+
+    fn part_2(grid: &Grid) -> usize {
+        let mut grid = grid.clone();
+        grid.collapse();
+        grid.count_chain_reactions()
+    }
 }
 
-fn main() {
-    use utils::PrintMode;
-    utils::run::<_, _>(
-        &[
-            ("day22/sample_input.txt", PrintMode::None),
-            ("day22/input.txt", PrintMode::None),
-        ],
-        &[part_1, part_2],
-    );
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 22,
+    inputs: &[
+        ("day22/sample_input.txt", PrintMode::None),
+        ("day22/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day22>,
+};
+
+utils::answer_tests! {
+    Day22;
+    sample_input => "day22/sample_input.txt",
+    input => "day22/input.txt",
 }
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use utils::grid::Grid;
+use utils::search;
+use utils::vec::Vec2i;
+use utils::{Day, ParseError, ParseErrorKind, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trails {
+    grid: Grid<char>,
+}
+
+impl ParseInput for Trails {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(s, |c| match c {
+            '.' | '#' | '<' | '>' | '^' | 'v' => Ok(c),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        })?;
+        Ok(Self { grid })
+    }
+
+    fn summary(&self) -> Option<String> {
+        let slopes = self
+            .grid
+            .cells()
+            .iter()
+            .filter(|x| !matches!(x, '.' | '#'))
+            .count();
+        Some(format!(
+            "{}x{} grid, {slopes} slopes",
+            self.grid.height(),
+            self.grid.width()
+        ))
+    }
+
+    fn display(&self) -> Option<String> {
+        Some(self.grid.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Graph {
+    edges: Vec<Vec<(usize, usize)>>,
+    start_node: usize,
+    goal_node: usize,
+}
+
+impl Graph {
+    fn longest_path(&self) -> usize {
+        let mut distances = vec![0; self.edges.len()];
+        let mut stack = Vec::new();
+        stack.push((self.start_node, 0));
+        while let Some((node, distance)) = stack.pop() {
+            for (next_node, edge_cost) in &self.edges[node] {
+                let next_distance = distance + edge_cost;
+                if next_distance > distances[*next_node] {
+                    distances[*next_node] = next_distance;
+                    stack.push((*next_node, next_distance));
+                }
+            }
+        }
+        distances[self.goal_node]
+    }
+
+    fn make_cyclic(&mut self) {
+        let edges_clone = self.edges.clone();
+        for (src, src_edges) in edges_clone.iter().enumerate() {
+            for (dest, cost) in src_edges.iter() {
+                self.edges[*dest].push((src, *cost));
+            }
+        }
+    }
+
+    fn cyclic_longest_path(&self) -> usize {
+        let mut max_distance = 0;
+        let mut stack = Vec::new();
+        stack.push((vec![self.start_node], 0));
+        while let Some((path, distance)) = stack.pop() {
+            if utils::cancelled() {
+                break;
+            }
+            let node = path.last().unwrap();
+            for (next_node, edge_cost) in &self.edges[*node] {
+                if *next_node == self.goal_node {
+                    max_distance = max_distance.max(distance + edge_cost);
+                } else {
+                    if !path.iter().any(|x| x == next_node) {
+                        let mut path = path.clone();
+                        path.push(*next_node);
+                        stack.push((path, distance + edge_cost));
+                    }
+                }
+            }
+        }
+        max_distance
+    }
+}
+
+impl From<Grid<char>> for Graph {
+    /// Builds the graph of the junctions, where more than two paths meet, with the start and the
+    /// goal. Edges follow the paths between them, which can only be walked down the slopes.
+    fn from(grid: Grid<char>) -> Self {
+        let slope_to_dir = |slope| match slope {
+            '<' => Some(Vec2i::LEFT),
+            '>' => Some(Vec2i::RIGHT),
+            '^' => Some(Vec2i::UP),
+            'v' => Some(Vec2i::DOWN),
+            _ => None,
+        };
+        let paths = |pos: Vec2i| grid.neighbours4(pos).filter(|x| grid[*x] != '#');
+
+        let start_pos = Vec2i::RIGHT;
+        let goal_pos = grid.size() - Vec2i::new(2, 1);
+        let mut nodes = vec![start_pos];
+        nodes.extend(
+            grid.positions()
+                .filter(|pos| grid[*pos] != '#' && paths(*pos).count() > 2),
+        );
+        nodes.push(goal_pos);
+        let indices: HashMap<Vec2i, usize> =
+            nodes.iter().enumerate().map(|(i, pos)| (*pos, i)).collect();
+
+        let edges = nodes
+            .iter()
+            .map(|node| {
+                // Walks from the node until reaching the next ones.
+                let reached = search::bfs_within(*node, usize::MAX, |pos| {
+                    let is_other_node = pos != node && indices.contains_key(pos);
+                    paths(*pos)
+                        .filter(|next_pos| {
+                            let dir = *next_pos - *pos;
+                            !is_other_node
+                                && slope_to_dir(grid[*pos]).is_none_or(|x| x == dir)
+                                && slope_to_dir(grid[*next_pos]).is_none_or(|x| x == dir)
+                        })
+                        .collect::<Vec<_>>()
+                });
+                let mut edges: Vec<(usize, usize)> = reached
+                    .into_iter()
+                    .filter(|(pos, _)| pos != node)
+                    .filter_map(|(pos, distance)| Some((*indices.get(&pos)?, distance)))
+                    .collect();
+                edges.sort();
+                edges
+            })
+            .collect();
+        Self {
+            edges,
+            start_node: 0,
+            goal_node: nodes.len() - 1,
+        }
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Trails;
+    type Answer = usize;
+
+    fn part_1(trails: &Trails) -> usize {
+        Graph::from(trails.grid.clone()).longest_path()
+    }
+
+    fn part_2(trails: &Trails) -> usize {
+        let mut graph = Graph::from(trails.grid.clone());
+        graph.make_cyclic();
+        graph.cyclic_longest_path()
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 23,
+    inputs: &[
+        ("day23/sample_input.txt", PrintMode::None),
+        ("day23/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day23>,
+};
+
+utils::answer_tests! {
+    Day23;
+    sample_input => "day23/sample_input.txt",
+    #[ignore = "slow in debug builds"]
+    input => "day23/input.txt",
+}
@@ -0,0 +1,43 @@
+use utils::{Day, ParseError, ParseInput, PrintMode, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Input {}
+
+impl ParseInput for Input {
+    fn parse(_s: &str) -> Result<Self, ParseError> {
+        Ok(Self {})
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Input;
+    type Answer = Option<u64>;
+
+    fn part_1(_input: &Input) -> Option<u64> {
+        None
+    }
+
+    fn part_2(_input: &Input) -> Option<u64> {
+        None
+    }
+}
+
+pub const DAY: Day = Day {
+    year: 2023,
+    number: 24,
+    inputs: &[
+        ("day24/sample_input.txt", PrintMode::Debug),
+        ("day24/input.txt", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day24>,
+};
+
+utils::answer_tests! {
+    Day24;
+    #[ignore = "no answers in data/2023/day24/sample_input.answers yet"]
+    sample_input => "day24/sample_input.txt",
+    #[ignore = "no answers in data/2023/day24/input.answers yet"]
+    input => "day24/input.txt",
+}
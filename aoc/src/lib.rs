@@ -0,0 +1,40 @@
+use utils::Day;
+
+/// All the days that can be run from the command line, in calendar order across years.
+pub const DAYS: &[Day] = &[
+    y2023_day01::DAY,
+    y2023_day02::DAY,
+    y2023_day03::DAY,
+    y2023_day04::DAY,
+    y2023_day05::DAY,
+    y2023_day06::DAY,
+    y2023_day07::DAY,
+    y2023_day08::DAY,
+    y2023_day09::DAY,
+    y2023_day10::DAY,
+    y2023_day11::DAY,
+    y2023_day12::DAY,
+    y2023_day13::DAY,
+    y2023_day14::DAY,
+    y2023_day15::DAY,
+    y2023_day16::DAY,
+    y2023_day17::DAY,
+    y2023_day18::DAY,
+    y2023_day19::DAY,
+    y2023_day20::DAY,
+    y2023_day21::DAY,
+    y2023_day22::DAY,
+    y2023_day23::DAY,
+    y2023_day24::DAY,
+    y2023_day25::DAY,
+];
+
+pub fn find_day(year: u32, number: u32) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.year == year && day.number == number)
+}
+
+/// The most recent year with registered days, which is used when none is specified.
+pub fn latest_year() -> Option<u32> {
+    DAYS.iter().map(|day| day.year).max()
+}
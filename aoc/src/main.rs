@@ -0,0 +1,417 @@
+mod scaffold;
+
+use utils::bench::format_duration;
+use utils::output::{self, OutputFormat};
+use utils::submit::{Outcome, Submitter};
+use utils::{Answer, BenchOptions, Day, PrintMode, Report, RunOptions};
+
+const USAGE: &str = "\
+Usage: aoc [--year <YEAR>] --day <DAY> [--part <PART>] [--input <PATH>] [<OUTPUT OPTIONS>]
+       aoc [--year <YEAR>] --all [--part <PART>] [<OUTPUT OPTIONS>]
+       aoc new [--year <YEAR>] <DAY>
+       aoc submit [--year <YEAR>] --day <DAY> --part <PART> [--answer <ANSWER>]
+
+Commands:
+  new <DAY>       Create the crate of a new day with placeholder data files, and register it in
+                  the workspace and the runner
+  submit          Submit the answer of a part on the day's puzzle input (or the given answer) to
+                  the puzzle website. Each attempt is recorded in data/<YEAR>/dayNN/submissions.log,
+                  and answers that cannot be right given the previous attempts are not sent
+
+Options:
+  --year <YEAR>   Year of the days to run or create (defaults to the latest registered year, or
+                  to every year with '--all')
+  --day <DAY>     Day to run (1-25)
+  --part <PART>   Only run the given part (1 or 2)
+  --input <PATH>  Input file, relative to the year's data directory (data/<YEAR>) unless
+                  absolute, or '-' to read from the standard input (defaults to the day's inputs)
+  --all           Run every registered day on its default inputs
+  --timeout <SECS>
+                  Give up on parts that take longer than this (e.g. '10' or '0.5'), and report
+                  them as timed out
+  --jobs <N>      Run the days, inputs and parts on up to N threads. The output is printed in the
+                  same order as when running them one at a time, followed by the wall-clock and
+                  CPU time of the run
+  --help          Print this message
+
+Output options:
+  --format <FMT>  Output format: 'text' (default), 'json' (one object per line) or 'csv'.
+                  Other messages are written to the standard error with 'json' and 'csv'
+  --print <MODE>  What to show about each input: 'none', 'summary', 'display', 'debug' or
+                  'trace' (defaults to the mode set for each input)
+  --bench         Benchmark the parsing and each part, then print a summary table
+  --validate      Instead of solving the inputs, print their summary and check the assumptions
+                  of the solutions on them
+
+Bench options:
+  --warmup <N>      Runs before measuring (default: 1)
+  --iterations <N>  Measured runs (default: 10)
+
+Environment:
+  AOC_SESSION     Session cookie of the puzzle website, used to submit answers and to download
+                  the missing puzzle inputs (data/<YEAR>/dayNN/input.txt)
+  AOC_BASE_URL    URL of the puzzle website (default: https://adventofcode.com)";
+
+#[derive(Debug, Clone, Default)]
+struct Args {
+    year: Option<u32>,
+    day: Option<u32>,
+    part: Option<usize>,
+    input: Option<String>,
+    all: bool,
+    bench: Option<BenchOptions>,
+    format: OutputFormat,
+    print_mode: Option<PrintMode>,
+    timeout: Option<std::time::Duration>,
+    /// Number of threads, if running in parallel.
+    jobs: Option<usize>,
+    validate: bool,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut ans = Self::default();
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for '{name}'"))
+            };
+            match arg.as_str() {
+                "--year" => ans.year = Some(parse_year(&value("--year")?)?),
+                "--day" => {
+                    let day = value("--day")?;
+                    ans.day = Some(day.parse().map_err(|_| format!("Invalid day '{day}'"))?);
+                }
+                "--part" => {
+                    let part = value("--part")?;
+                    ans.part = Some(part.parse().map_err(|_| format!("Invalid part '{part}'"))?);
+                }
+                "--input" => ans.input = Some(value("--input")?),
+                "--all" => ans.all = true,
+                "--timeout" => {
+                    let timeout = value("--timeout")?;
+                    ans.timeout = Some(
+                        timeout
+                            .parse()
+                            .ok()
+                            .filter(|x| *x > 0.0)
+                            .and_then(|x| std::time::Duration::try_from_secs_f64(x).ok())
+                            .ok_or_else(|| format!("Invalid timeout '{timeout}'"))?,
+                    );
+                }
+                "--jobs" => {
+                    let jobs = value("--jobs")?;
+                    ans.jobs = Some(
+                        jobs.parse()
+                            .ok()
+                            .filter(|x| *x > 0)
+                            .ok_or_else(|| format!("Invalid number of jobs '{jobs}'"))?,
+                    );
+                }
+                "--format" => ans.format = value("--format")?.parse()?,
+                "--print" => ans.print_mode = Some(value("--print")?.parse()?),
+                "--validate" => ans.validate = true,
+                "--bench" => {
+                    ans.bench.get_or_insert_with(BenchOptions::default);
+                }
+                "--warmup" => {
+                    let warmup = value("--warmup")?;
+                    ans.bench.get_or_insert_with(BenchOptions::default).warmup = warmup
+                        .parse()
+                        .map_err(|_| format!("Invalid warmup count '{warmup}'"))?;
+                }
+                "--iterations" => {
+                    let iterations = value("--iterations")?;
+                    let iterations = iterations
+                        .parse()
+                        .ok()
+                        .filter(|x| *x > 0)
+                        .ok_or_else(|| format!("Invalid iteration count '{iterations}'"))?;
+                    ans.bench
+                        .get_or_insert_with(BenchOptions::default)
+                        .iterations = iterations;
+                }
+                _ => return Err(format!("Unrecognized argument '{arg}'")),
+            }
+        }
+
+        if ans.all && (ans.day.is_some() || ans.input.is_some()) {
+            return Err(String::from(
+                "'--all' cannot be combined with '--day' or '--input'",
+            ));
+        }
+        if !ans.all && ans.day.is_none() {
+            return Err(String::from("Either '--day' or '--all' must be specified"));
+        }
+        if ans.bench.is_some() && !ans.format.is_text() {
+            return Err(String::from(
+                "'--bench' is only supported with the text output format",
+            ));
+        }
+        if ans.bench.is_some() && ans.jobs.is_some_and(|x| x > 1) {
+            return Err(String::from(
+                "'--bench' cannot be combined with '--jobs', as it would skew the timings",
+            ));
+        }
+        if ans.bench.is_some() && ans.validate {
+            return Err(String::from(
+                "'--bench' cannot be combined with '--validate'",
+            ));
+        }
+        if ans.part.is_some_and(|part| part != 1 && part != 2) {
+            return Err(String::from("'--part' must be either 1 or 2"));
+        }
+        Ok(ans)
+    }
+}
+
+fn run(args: &Args) -> Result<Report, String> {
+    let options = RunOptions {
+        part: args.part,
+        bench: args.bench,
+        format: args.format,
+        print_mode: args.print_mode,
+        year: None,
+        day: None,
+        timeout: args.timeout,
+        parallel: args.jobs.is_some_and(|x| x > 1),
+        validate: args.validate,
+    };
+    if let Some(header) = args.format.header() {
+        println!("{header}");
+    }
+
+    // Each input of each day to run, in order.
+    let tasks: Vec<(&Day, (&str, PrintMode))> = if args.all {
+        aoc::DAYS
+            .iter()
+            .filter(|day| args.year.is_none_or(|year| day.year == year))
+            .flat_map(|day| day.inputs.iter().map(move |input| (day, *input)))
+            .collect()
+    } else {
+        let year = default_year(args.year)?;
+        let number = args.day.unwrap();
+        let day = aoc::find_day(year, number)
+            .ok_or_else(|| format!("Day {number} of {year} is not registered"))?;
+        match &args.input {
+            Some(input) => vec![(day, (input.as_str(), PrintMode::None))],
+            None => day.inputs.iter().map(|input| (day, *input)).collect(),
+        }
+    };
+
+    let mut report = Report::default();
+    let mut current_day = None;
+    let mut print_heading = |day: &Day| {
+        if args.all && current_day != Some((day.year, day.number)) {
+            current_day = Some((day.year, day.number));
+            output::emit(
+                args.format.message_stream(),
+                format_args!("=== {} Day {:02} ===", day.year, day.number),
+            );
+        }
+    };
+    match args.jobs {
+        Some(jobs) if jobs > 1 => utils::pool::map_in_order(
+            tasks,
+            jobs,
+            |(day, input)| (day, output::capture(|| day.run(&[input], &options))),
+            |(day, (day_report, captured))| {
+                print_heading(day);
+                captured.print();
+                report += day_report;
+            },
+        ),
+        _ => {
+            for (day, input) in tasks {
+                print_heading(day);
+                report += day.run(&[input], &options);
+            }
+        }
+    }
+    Ok(report)
+}
+
+fn parse_year(year: &str) -> Result<u32, String> {
+    year.parse()
+        .ok()
+        .filter(|x| *x >= 2015)
+        .ok_or_else(|| format!("Invalid year '{year}'"))
+}
+
+/// The given year, or else the latest registered one.
+fn default_year(year: Option<u32>) -> Result<u32, String> {
+    year.or_else(aoc::latest_year)
+        .ok_or_else(|| String::from("No days are registered, '--year' must be specified"))
+}
+
+fn new_day(args: &[String]) -> Result<(), String> {
+    let (mut year, mut day) = (None, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => {
+                let year_arg = args
+                    .next()
+                    .ok_or_else(|| String::from("Missing value for '--year'"))?;
+                year = Some(parse_year(year_arg)?);
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unrecognized argument '{arg}'")),
+            _ if day.is_none() => {
+                day = Some(
+                    arg.parse::<u32>()
+                        .map_err(|_| format!("Invalid day '{arg}'"))?,
+                );
+            }
+            _ => return Err(String::from("'new' takes a single day")),
+        }
+    }
+    let year = default_year(year)?;
+    let day = day.ok_or_else(|| String::from("'new' requires a day"))?;
+    let root = scaffold::workspace_root()?;
+    for path in scaffold::new_day(&root, year, day)? {
+        println!(
+            "Wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+    Ok(())
+}
+
+/// Submits an answer, returning whether it was right. Errors in the arguments are returned, while
+/// the submission failures are reported here.
+fn submit(args: &[String]) -> Result<bool, String> {
+    let (mut year, mut day, mut part, mut answer) = (None, None, None, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{name}'"))
+        };
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(value("--year")?)?),
+            "--day" => {
+                let day_arg = value("--day")?;
+                day = Some(
+                    day_arg
+                        .parse::<u32>()
+                        .map_err(|_| format!("Invalid day '{day_arg}'"))?,
+                );
+            }
+            "--part" => {
+                let part_arg = value("--part")?;
+                part = Some(
+                    part_arg
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|x| *x == 1 || *x == 2)
+                        .ok_or_else(|| String::from("'--part' must be either 1 or 2"))?,
+                );
+            }
+            "--answer" => answer = Some(Answer::from(value("--answer")?.as_str())),
+            _ => return Err(format!("Unrecognized argument '{arg}'")),
+        }
+    }
+    let year = default_year(year)?;
+    let number = day.ok_or_else(|| String::from("'submit' requires '--day'"))?;
+    let part = part.ok_or_else(|| String::from("'submit' requires '--part'"))?;
+    let day = aoc::find_day(year, number)
+        .ok_or_else(|| format!("Day {number} of {year} is not registered"))?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let input = utils::inputs::input_path(number);
+            let options = RunOptions {
+                part: Some(part),
+                ..RunOptions::default()
+            };
+            let report = day.run(&[(&input, PrintMode::None)], &options);
+            match report.answers.into_iter().find(|x| x.0 == part) {
+                Some((_, answer)) if answer != Answer::None => answer,
+                _ => {
+                    eprintln!("error: part {part} has no answer to submit");
+                    return Ok(false);
+                }
+            }
+        }
+    };
+
+    println!("Submitting '{answer}' to part {part} of day {number} of {year}");
+    match Submitter::from_env().submit(year, number, part, &answer) {
+        Ok(feedback) => {
+            println!("{}: {}", feedback.outcome.name(), feedback.message);
+            Ok(feedback.outcome == Outcome::Correct)
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            Ok(false)
+        }
+    }
+}
+
+fn main() -> std::process::ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|x| x == "--help" || x == "-h") {
+        println!("{USAGE}");
+        return std::process::ExitCode::SUCCESS;
+    }
+    if args.first().is_some_and(|x| x == "new") {
+        return match new_day(&args[1..]) {
+            Ok(()) => std::process::ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("error: {error}\n\n{USAGE}");
+                std::process::ExitCode::from(2)
+            }
+        };
+    }
+
+    if args.first().is_some_and(|x| x == "submit") {
+        return match submit(&args[1..]) {
+            Ok(true) => std::process::ExitCode::SUCCESS,
+            Ok(false) => std::process::ExitCode::FAILURE,
+            Err(error) => {
+                eprintln!("error: {error}\n\n{USAGE}");
+                std::process::ExitCode::from(2)
+            }
+        };
+    }
+
+    let args = match Args::parse(args.into_iter()) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return std::process::ExitCode::from(2);
+        }
+    };
+    let wall_time = std::time::Instant::now();
+    let cpu_time = utils::bench::cpu_time();
+    match run(&args) {
+        Ok(report) => {
+            let wall_time = wall_time.elapsed();
+            let cpu_time = cpu_time
+                .zip(utils::bench::cpu_time())
+                .map(|(start, end)| end - start);
+            if !report.benchmarks.is_empty() {
+                utils::bench::print_summary(&report.benchmarks);
+                println!();
+            }
+            let mut summary = format!("{} passed, {} failed", report.passed, report.failed);
+            if report.timed_out > 0 {
+                summary += &format!(", {} timed out", report.timed_out);
+            }
+            if args.jobs.is_some() {
+                summary += &format!("\nWall-clock time: {}", format_duration(wall_time));
+                if let Some(cpu_time) = cpu_time {
+                    summary += &format!(", CPU time: {}", format_duration(cpu_time));
+                }
+            }
+            output::emit(args.format.message_stream(), format_args!("{summary}"));
+            report.into()
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            std::process::ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Generates the crate, data files and registrations of a new day.
+
+use std::path::{Path, PathBuf};
+
+/// Returns the root of the workspace, looked up from the current directory like the data directory.
+pub fn workspace_root() -> Result<PathBuf, String> {
+    let dir = std::env::current_dir().map_err(|error| error.to_string())?;
+    dir.ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| String::from("Could not find the workspace root"))
+}
+
+/// Creates the crate and the data files of `day` of `year` under `root`, and registers it in the
+/// workspace and in the runner. Fails without changing anything if the day already exists. Returns
+/// the created and updated files.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day '{day}'"));
+    }
+    let dir = format!("{year}/day{day:02}");
+    let name = format!("y{year}-day{day:02}");
+    let crate_name = name.replace('-', "_");
+    let crate_dir = root.join(&dir);
+    let data_dir = root.join("data").join(&dir);
+    for dir in [&crate_dir, &data_dir] {
+        if dir.exists() {
+            return Err(format!("{dir:?} already exists"));
+        }
+    }
+
+    // Compute every edit before writing anything, so that a failure leaves the tree untouched.
+    let edits = [
+        (
+            root.join("Cargo.toml"),
+            format!("    \"{dir}\","),
+            is_entry("    \""),
+        ),
+        (
+            root.join("aoc/Cargo.toml"),
+            format!("{name} = {{ path = \"../{dir}\" }}"),
+            is_entry("y"),
+        ),
+        (
+            root.join("aoc/src/lib.rs"),
+            format!("    {crate_name}::DAY,"),
+            is_entry("    y"),
+        ),
+    ];
+    let mut updated = Vec::new();
+    for (path, line, is_entry) in &edits {
+        let text = std::fs::read_to_string(path).map_err(|error| format!("{path:?}: {error}"))?;
+        if text.split('\n').any(|x| x == line) {
+            return Err(format!("{name} is already registered in {path:?}"));
+        }
+        let text = insert_sorted(&text, line, is_entry)
+            .ok_or_else(|| format!("Could not find where to register {name} in {path:?}"))?;
+        updated.push((path.clone(), text));
+    }
+
+    let created = [
+        (crate_dir.join("Cargo.toml"), manifest(&name)),
+        (crate_dir.join("src/main.rs"), main_rs(&crate_name)),
+        (crate_dir.join("src/lib.rs"), lib_rs(year, day)),
+        (data_dir.join("sample_input.txt"), String::new()),
+        (
+            data_dir.join("sample_input.answers"),
+            answers_file("sample_input.txt"),
+        ),
+        (data_dir.join("input.txt"), String::new()),
+        (data_dir.join("input.answers"), answers_file("input.txt")),
+    ];
+
+    let mut files = Vec::new();
+    for (path, text) in created.into_iter().chain(updated) {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|error| format!("{dir:?}: {error}"))?;
+        }
+        std::fs::write(&path, text).map_err(|error| format!("{path:?}: {error}"))?;
+        files.push(path);
+    }
+    Ok(files)
+}
+
+/// Matches the lines registering a day in a list, which start with `prefix` and its year.
+fn is_entry(prefix: &str) -> impl Fn(&str) -> bool + '_ {
+    move |line| {
+        line.strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+    }
+}
+
+/// Inserts `line` among the lines for which `is_entry` holds, keeping them sorted. Returns `None`
+/// if there is no such line.
+fn insert_sorted(text: &str, line: &str, is_entry: impl Fn(&str) -> bool) -> Option<String> {
+    let lines: Vec<&str> = text.split('\n').collect();
+    let entries: Vec<usize> = (0..lines.len()).filter(|i| is_entry(lines[*i])).collect();
+    let idx = entries
+        .iter()
+        .find(|i| lines[**i] > line)
+        .copied()
+        .or_else(|| entries.last().map(|i| i + 1))?;
+    let mut lines = lines;
+    lines.insert(idx, line);
+    Some(lines.join("\n"))
+}
+
+fn manifest(name: &str) -> String {
+    format!(
+        "[package]
+name = \"{name}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+utils = {{ path = \"../../utils\" }}
+"
+    )
+}
+
+fn main_rs(name: &str) -> String {
+    format!(
+        "fn main() -> std::process::ExitCode {{
+    {name}::DAY.run_all().into()
+}}
+"
+    )
+}
+
+/// The expected answers of the input `name`, with none known yet.
+fn answers_file(name: &str) -> String {
+    format!(
+        "# Expected answers of {name}, such as `1: 42`. Parts without a line are not checked.
+"
+    )
+}
+
+/// The solution of a day that is not solved yet: parts have no answer until they are implemented.
+fn lib_rs(year: u32, day: u32) -> String {
+    format!(
+        "use utils::{{Day, ParseError, ParseInput, PrintMode, Solution}};
+
+#[derive(Debug, Clone)]
+pub struct Input {{}}
+
+impl ParseInput for Input {{
+    fn parse(_s: &str) -> Result<Self, ParseError> {{
+        Ok(Self {{}})
+    }}
+}}
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Input;
+    type Answer = Option<u64>;
+
+    fn part_1(_input: &Input) -> Option<u64> {{
+        None
+    }}
+
+    fn part_2(_input: &Input) -> Option<u64> {{
+        None
+    }}
+}}
+
+pub const DAY: Day = Day {{
+    year: {year},
+    number: {day},
+    inputs: &[
+        (\"day{day:02}/sample_input.txt\", PrintMode::Debug),
+        (\"day{day:02}/input.txt\", PrintMode::None),
+    ],
+    runner: utils::run_with_options::<Day{day:02}>,
+}};
+
+utils::answer_tests! {{
+    Day{day:02};
+    #[ignore = \"no answers in data/{year}/day{day:02}/sample_input.answers yet\"]
+    sample_input => \"day{day:02}/sample_input.txt\",
+    #[ignore = \"no answers in data/{year}/day{day:02}/input.answers yet\"]
+    input => \"day{day:02}/input.txt\",
+}}
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_keeps_entries_sorted() {
+        let text = "[\n    \"utils\",\n    \"2023/day01\",\n    \"2023/day03\",\n]";
+        let is_entry = is_entry("    \"");
+        assert_eq!(
+            insert_sorted(text, "    \"2023/day02\",", &is_entry).unwrap(),
+            "[\n    \"utils\",\n    \"2023/day01\",\n    \"2023/day02\",\n    \"2023/day03\",\n]"
+        );
+        assert_eq!(
+            insert_sorted(text, "    \"2024/day01\",", &is_entry).unwrap(),
+            "[\n    \"utils\",\n    \"2023/day01\",\n    \"2023/day03\",\n    \"2024/day01\",\n]"
+        );
+        assert_eq!(
+            insert_sorted("[\n]", "    \"2023/day01\",", &is_entry),
+            None
+        );
+    }
+
+    /// Creates a workspace with day 1 of 2023 registered, in a new temporary directory.
+    fn temp_workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        std::fs::remove_dir_all(&root).ok();
+        let write = |path: &str, text: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        };
+        write(
+            "Cargo.toml",
+            "[workspace]\n\nmembers = [\n    \"utils\",\n    \"aoc\",\n    \"2023/day01\",\n]",
+        );
+        write(
+            "aoc/Cargo.toml",
+            "[dependencies]\nutils = { path = \"../utils\" }\ny2023-day01 = { path = \"../2023/day01\" }\n",
+        );
+        write(
+            "aoc/src/lib.rs",
+            "pub const DAYS: &[Day] = &[\n    y2023_day01::DAY,\n];\n",
+        );
+        std::fs::create_dir_all(root.join("2023/day01")).unwrap();
+        root
+    }
+
+    #[test]
+    fn new_day_creates_and_registers_the_crate() {
+        let root = temp_workspace("scaffold");
+        let files = new_day(&root, 2023, 2).unwrap();
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(files.len(), 10);
+        assert!(read("Cargo.toml").ends_with("    \"2023/day01\",\n    \"2023/day02\",\n]"));
+        assert!(read("aoc/Cargo.toml").ends_with("y2023-day02 = { path = \"../2023/day02\" }\n"));
+        assert!(read("aoc/src/lib.rs").contains("    y2023_day01::DAY,\n    y2023_day02::DAY,\n"));
+        assert!(read("2023/day02/src/lib.rs").contains("impl Solution for Day02 {"));
+        assert!(read("2023/day02/src/main.rs").contains("y2023_day02::DAY"));
+        assert_eq!(read("data/2023/day02/input.txt"), "");
+
+        new_day(&root, 2024, 1).unwrap();
+        assert!(
+            read("aoc/src/lib.rs").ends_with("    y2023_day02::DAY,\n    y2024_day01::DAY,\n];\n")
+        );
+        assert!(read("2024/day01/src/lib.rs").contains("    year: 2024,\n    number: 1,"));
+
+        assert!(new_day(&root, 2023, 2).is_err());
+        assert!(new_day(&root, 2023, 1).is_err());
+        assert!(new_day(&root, 2023, 26).is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn inputs_of_new_days_are_fetched() {
+        use utils::http::{TcpClient, Website};
+        use utils::inputs::InputProvider;
+        use utils::testing::MockServer;
+
+        let root = temp_workspace("scaffold-fetch");
+        new_day(&root, 2023, 2).unwrap();
+        let server = MockServer::start(&[("/2023/day/2/input", 200, "1 2 3\n")]);
+        let website = Website::new(
+            &server.url(),
+            Some(String::from("token")),
+            Box::new(TcpClient),
+        );
+        let provider = InputProvider::new(root.join("data"), website);
+
+        let path = provider.get(2023, 2).unwrap();
+        assert_eq!(path, root.join("data/2023/day02/input.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        assert_eq!(provider.get(2023, 2).unwrap(), path);
+        assert_eq!(server.requests().len(), 1);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
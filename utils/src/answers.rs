@@ -0,0 +1,229 @@
+use crate::parse::{ParseError, ParseErrorKind, ParseInput};
+use crate::InputSource;
+use std::collections::BTreeMap;
+
+/// The answer to a part, whatever type the solution computes it with.
+///
+/// Solutions can return any type converting into it, and the runner compares, prints and
+/// serializes every answer through its `Display` form (e.g. `42`, `none`, `[16, 42]`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// The part has no answer for this input.
+    None,
+    Int(i64),
+    /// An integer that doesn't fit in an `i64`. Conversions only use it when needed, so that equal
+    /// values are always represented the same way.
+    BigInt(i128),
+    Text(String),
+    Multi(Vec<Answer>),
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Int(x) => write!(f, "{x}"),
+            Self::BigInt(x) => write!(f, "{x}"),
+            Self::Text(x) => write!(f, "{x}"),
+            Self::Multi(answers) => {
+                write!(f, "[")?;
+                for (i, answer) in answers.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{answer}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_small_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(x: $t) -> Self {
+                    Self::Int(i64::from(x))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_large_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(x: $t) -> Self {
+                    match i64::try_from(x) {
+                        Ok(x) => Self::Int(x),
+                        Err(_) => Self::BigInt(x as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_small_int!(i8, i16, i32, i64, u8, u16, u32);
+impl_from_large_int!(isize, usize, u64);
+
+impl From<i128> for Answer {
+    fn from(x: i128) -> Self {
+        match i64::try_from(x) {
+            Ok(x) => Self::Int(x),
+            Err(_) => Self::BigInt(x),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(x: String) -> Self {
+        Self::Text(x)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(x: &str) -> Self {
+        Self::Text(String::from(x))
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(x: Option<T>) -> Self {
+        x.map_or(Self::None, Into::into)
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(x: Vec<T>) -> Self {
+        Self::Multi(x.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Expected answers for an input, stored in a sidecar file with the `answers` extension next to
+/// the input itself (e.g. `data/2023/day01/input.answers` for `data/2023/day01/input.txt`).
+///
+/// Each non-empty line has the form `<part>: <answer>`, where the answer is written the same way
+/// the runner displays it. Lines starting with `#` are comments. Parts without a line are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    answers: BTreeMap<usize, String>,
+}
+
+impl ExpectedAnswers {
+    pub fn path(source: &InputSource) -> Option<std::path::PathBuf> {
+        match source {
+            InputSource::Stdin => None,
+            InputSource::File(path) => Some(path.with_extension("answers")),
+        }
+    }
+
+    pub fn get(&self, part: usize) -> Option<&str> {
+        self.answers.get(&part).map(|x| &x[..])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl ParseInput for ExpectedAnswers {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut answers = BTreeMap::new();
+        for line in crate::parse::lines(s) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let (part, answer) = line.split_once(text, ":")?;
+            let part = line.parse(part.trim())?;
+            if answers.insert(part, String::from(answer.trim())).is_some() {
+                return Err(line.error(
+                    text,
+                    ParseErrorKind::Other(format!("duplicate answer for part {part}")),
+                ));
+            }
+        }
+        Ok(Self { answers })
+    }
+}
+
+/// Whether an answer matches the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Unchecked,
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// The part went over its time budget.
+    Timeout,
+    Panicked(crate::panic::Panic),
+}
+
+impl Verdict {
+    /// Compares the answer with the expected one, written as it is displayed.
+    pub fn new(expected: Option<&str>, answer: &Answer) -> Self {
+        match expected {
+            None => Self::Unchecked,
+            Some(expected) if expected == answer.to_string() => Self::Pass,
+            Some(expected) => Self::Fail {
+                expected: String::from(expected),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unchecked => Ok(()),
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail { expected } => write!(f, "FAIL (expected '{expected}')"),
+            Self::Timeout => write!(f, "TIMEOUT"),
+            Self::Panicked(panic) => write!(f, "FAILED ({panic})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Answer::from(42u64), Answer::from(42i32));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(None::<u32>), Answer::None);
+        assert_eq!(Answer::from(Some(7usize)).to_string(), "7");
+        assert_eq!(Answer::from(vec![Some(1), None]).to_string(), "[1, none]");
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+    }
+
+    #[test]
+    fn verdict_compares_displayed_answers() {
+        let answer = Answer::from(Some(142));
+        assert_eq!(Verdict::new(Some("142"), &answer), Verdict::Pass);
+        assert_eq!(Verdict::new(None, &answer), Verdict::Unchecked);
+        assert_eq!(
+            Verdict::new(Some("Some(142)"), &answer),
+            Verdict::Fail {
+                expected: String::from("Some(142)")
+            }
+        );
+    }
+
+    #[test]
+    fn answers_are_next_to_input_files() {
+        let source = InputSource::from_path("-", Some(2023));
+        assert_eq!(source, InputSource::Stdin);
+        assert_eq!(ExpectedAnswers::path(&source), None);
+        let source = InputSource::File(std::path::PathBuf::from("data/2023/day01/input.txt"));
+        assert_eq!(
+            ExpectedAnswers::path(&source),
+            Some(std::path::PathBuf::from("data/2023/day01/input.answers"))
+        );
+    }
+}
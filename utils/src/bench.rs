@@ -0,0 +1,180 @@
+use std::time::{Duration, Instant};
+
+/// How many times each measured step is run when benchmarking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Runs whose timings are discarded, to warm up caches before measuring.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            iterations: 10,
+        }
+    }
+}
+
+impl BenchOptions {
+    /// Runs `f` repeatedly and returns statistics about how long it took.
+    pub fn measure<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        for _ in 0..self.warmup {
+            std::hint::black_box(f());
+        }
+        let samples: Vec<Duration> = (0..self.iterations.max(1))
+            .map(|_| {
+                let time = Instant::now();
+                std::hint::black_box(f());
+                time.elapsed()
+            })
+            .collect();
+        Stats::new(&samples)
+    }
+}
+
+/// Summary of the timings of several runs of the same step.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().map(|x| x.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            samples
+                .iter()
+                .map(|x| (x.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Self {
+            iterations: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, mean {}, stddev {} ({} iterations)",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.stddev),
+            self.iterations
+        )
+    }
+}
+
+/// Benchmark of the parsing and the selected parts of a single input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    pub input: String,
+    pub parse: Stats,
+    /// Statistics for each benchmarked part, with its (1-based) number.
+    pub parts: Vec<(usize, Stats)>,
+}
+
+/// Formats a duration with a unit suited to its magnitude.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2} μs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+/// The CPU time used so far by the process, summed over all its threads, or `None` where it
+/// can't be read. It is read from `/proc/self/stat`, in clock ticks of 10 ms.
+pub fn cpu_time() -> Option<Duration> {
+    const TICKS_PER_SECOND: u64 = 100;
+    let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+    // The command name may contain spaces, so the fields are counted from its closing parenthesis:
+    // `utime` and `stime` are the 14th and 15th fields, with the state as the 3rd.
+    let mut fields = stat[stat.rfind(')')? + 1..].split_whitespace().skip(11);
+    let user: u64 = fields.next()?.parse().ok()?;
+    let system: u64 = fields.next()?.parse().ok()?;
+    Some(Duration::from_millis(
+        (user + system) * 1000 / TICKS_PER_SECOND,
+    ))
+}
+
+/// Prints a table with the median (± standard deviation) timings of every benchmarked input.
+pub fn print_summary(results: &[BenchResult]) {
+    let parts = results
+        .iter()
+        .flat_map(|x| x.parts.iter().map(|(part, _)| *part))
+        .max()
+        .unwrap_or(0);
+    let cell = |stats: &Stats| {
+        format!(
+            "{} ± {}",
+            format_duration(stats.median),
+            format_duration(stats.stddev)
+        )
+    };
+
+    let mut rows = vec![std::iter::once(String::from("Input"))
+        .chain(std::iter::once(String::from("Parse")))
+        .chain((1..=parts).map(|part| format!("Part {part}")))
+        .collect::<Vec<_>>()];
+    for result in results {
+        let mut row = vec![result.input.clone(), cell(&result.parse)];
+        for part in 1..=parts {
+            row.push(
+                result
+                    .parts
+                    .iter()
+                    .find(|(x, _)| *x == part)
+                    .map_or_else(|| String::from("-"), |(_, stats)| cell(stats)),
+            );
+        }
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|i| rows.iter().map(|row| row[i].chars().count()).max().unwrap())
+        .collect();
+    for (i, row) in rows.iter().enumerate() {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(x, width)| format!("{x:<width$}"))
+            .collect();
+        println!("{}", line.join(" | ").trim_end());
+        if i == 0 {
+            let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+            println!("{}", separator.join("-|-"));
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use crate::parse::{ParseError, ParseErrorKind, ParseInput};
+use crate::vec::Vec2i;
+
+/// Positions and offsets on a grid use `x` for the column and `y` for the row, growing downwards.
+impl Vec2i {
+    pub const UP: Self = Self::new(0, -1);
+    pub const DOWN: Self = Self::new(0, 1);
+    pub const LEFT: Self = Self::new(-1, 0);
+    pub const RIGHT: Self = Self::new(1, 0);
+
+    /// The offsets to the 4 orthogonal neighbours.
+    pub const ORTHOGONAL: [Self; 4] = [Self::LEFT, Self::RIGHT, Self::UP, Self::DOWN];
+
+    /// The offsets to the 8 orthogonal and diagonal neighbours.
+    pub const ADJACENT: [Self; 8] = [
+        Self::new(-1, -1),
+        Self::new(0, -1),
+        Self::new(1, -1),
+        Self::new(-1, 0),
+        Self::new(1, 0),
+        Self::new(-1, 1),
+        Self::new(0, 1),
+        Self::new(1, 1),
+    ];
+}
+
+/// A rectangular grid of cells stored row by row. Positions outside of the grid (including
+/// negative ones) are rejected by every accessor.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    size: Vec2i,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order. Panics if their number doesn't match the
+    /// size.
+    pub fn new(cells: Vec<T>, size: Vec2i) -> Self {
+        assert!(
+            size.x() >= 0 && size.y() >= 0 && cells.len() as i64 == size.x() * size.y(),
+            "{} cells don't fit a {}x{} grid",
+            cells.len(),
+            size.y(),
+            size.x()
+        );
+        Self { cells, size }
+    }
+
+    pub fn filled(size: Vec2i, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(vec![value; (size.x() * size.y()) as usize], size)
+    }
+
+    /// Builds a grid by calling `f` on each position, in row-major order.
+    pub fn from_fn(size: Vec2i, mut f: impl FnMut(Vec2i) -> T) -> Self {
+        let cells = (0..size.y())
+            .flat_map(|y| (0..size.x()).map(move |x| Vec2i::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Self::new(cells, size)
+    }
+
+    /// Parses a grid with one row per line, converting each character with `f`. All the rows
+    /// must have the same width.
+    pub fn parse_with<F>(s: &str, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseErrorKind>,
+    {
+        let mut cells = Vec::new();
+        let (mut width, mut height) = (0, 0);
+        for line in crate::parse::lines(s) {
+            if height == 0 {
+                width = line.text.chars().count();
+            } else {
+                line.check_width(width)?;
+            }
+            height += 1;
+            for (idx, c) in line.text.chars().enumerate() {
+                cells.push(f(c).map_err(|kind| ParseError::new(line.number, idx + 1, kind))?);
+            }
+        }
+        Ok(Self::new(cells, Vec2i::new(width as i64, height as i64)))
+    }
+
+    pub fn size(&self) -> Vec2i {
+        self.size
+    }
+
+    pub fn width(&self) -> usize {
+        self.size.x() as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.size.y() as usize
+    }
+
+    /// All the cells, in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn contains(&self, pos: Vec2i) -> bool {
+        pos.x() >= 0 && pos.x() < self.size.x() && pos.y() >= 0 && pos.y() < self.size.y()
+    }
+
+    /// The index in `cells` of the cell at `pos`.
+    pub fn index_of(&self, pos: Vec2i) -> Option<usize> {
+        self.contains(pos)
+            .then(|| (pos.y() * self.size.x() + pos.x()) as usize)
+    }
+
+    /// The position of the cell at `idx` in `cells`.
+    pub fn position_of(&self, idx: usize) -> Option<Vec2i> {
+        (idx < self.cells.len())
+            .then(|| Vec2i::new(idx as i64 % self.size.x(), idx as i64 / self.size.x()))
+    }
+
+    pub fn get(&self, pos: Vec2i) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2i) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// The position of the first cell (in row-major order) matching `predicate`.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Vec2i> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .and_then(|idx| self.position_of(idx))
+    }
+
+    /// All the positions of the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2i> {
+        let size = self.size;
+        (0..size.y()).flat_map(move |y| (0..size.x()).map(move |x| Vec2i::new(x, y)))
+    }
+
+    /// All the cells with their position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2i, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height()).then(|| &self.cells[y * self.width()..(y + 1) * self.width()])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height()).map(|y| self.row(y).unwrap())
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let (start, step) = if x < self.width() {
+            (x, self.width())
+        } else {
+            (self.cells.len(), 1)
+        };
+        self.cells[start..].iter().step_by(step)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width()).map(|x| self.column(x))
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Vec2i) -> impl Iterator<Item = Vec2i> + '_ {
+        Vec2i::ORTHOGONAL
+            .into_iter()
+            .map(move |offset| pos + offset)
+            .filter(|x| self.contains(*x))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: Vec2i) -> impl Iterator<Item = Vec2i> + '_ {
+        Vec2i::ADJACENT
+            .into_iter()
+            .map(move |offset| pos + offset)
+            .filter(|x| self.contains(*x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.cells.iter().map(f).collect(), self.size)
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(Vec2i::new(self.size.y(), self.size.x()), |pos| {
+            self[Vec2i::new(pos.y(), pos.x())].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(Vec2i::new(self.size.y(), self.size.x()), |pos| {
+            self[Vec2i::new(pos.y(), self.size.y() - 1 - pos.x())].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(Vec2i::new(self.size.y(), self.size.x()), |pos| {
+            self[Vec2i::new(self.size.x() - 1 - pos.y(), pos.x())].clone()
+        })
+    }
+}
+
+impl<T> std::ops::Index<Vec2i> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2i) -> &Self::Output {
+        let size = self.size;
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the {}x{} grid", size.y(), size.x()))
+    }
+}
+
+impl<T> std::ops::IndexMut<Vec2i> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2i) -> &mut Self::Output {
+        let size = self.size;
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the {}x{} grid", size.y(), size.x()))
+    }
+}
+
+impl<T> std::fmt::Display for Grid<T>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> ParseInput for Grid<T>
+where
+    T: TryFrom<char, Error = ParseErrorKind>,
+{
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        Self::parse_with(s, T::try_from)
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!("{}x{} grid", self.size.y(), self.size.x()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::parse_with(s, Ok).unwrap()
+    }
+
+    #[test]
+    fn parse_checks_row_width() {
+        let error = Grid::parse_with("abc\nde\n", Ok).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = Grid::parse_with("ab\ncd\n", |c| match c {
+            'd' => Err(ParseErrorKind::UnexpectedChar(c)),
+            _ => Ok(c),
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn indexing_is_bounds_checked() {
+        let grid = grid("ab\ncd\nef\n");
+        assert_eq!(grid.size(), Vec2i::new(2, 3));
+        assert_eq!(grid[Vec2i::new(1, 2)], 'f');
+        assert_eq!(grid.get(Vec2i::new(2, 0)), None);
+        assert_eq!(grid.get(Vec2i::new(-1, 1)), None);
+        assert_eq!(grid.find(|x| *x == 'd'), Some(Vec2i::new(1, 1)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid("ab\ncd\nef\n");
+        let rows: Vec<String> = grid.rows().map(|x| x.iter().collect()).collect();
+        assert_eq!(rows, ["ab", "cd", "ef"]);
+        let columns: Vec<String> = grid.columns().map(|x| x.collect()).collect();
+        assert_eq!(columns, ["ace", "bdf"]);
+        assert_eq!(grid.column(2).count(), 0);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = grid("abc\ndef\nghi\n");
+        assert_eq!(grid.neighbours4(Vec2i::ZERO).count(), 2);
+        assert_eq!(grid.neighbours8(Vec2i::ZERO).count(), 3);
+        assert_eq!(grid.neighbours4(Vec2i::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Vec2i::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = grid("abc\ndef\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+    }
+}
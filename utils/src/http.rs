@@ -0,0 +1,295 @@
+//! Requests to the puzzle website, through a client that can be replaced to test without it.
+
+use std::io::{Read, Write};
+
+/// Environment variable holding the session cookie of the account the requests are made for.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the URL of the puzzle website, e.g. to use a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Sent with every request, as asked by the puzzle website for automated tools.
+pub const USER_AGENT: &str = "aoc-utils";
+
+/// The response to a request, with its body as text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Performs the HTTP requests of a [`Website`], so that it can be pointed at a mock server or
+/// replaced in tests.
+pub trait HttpClient {
+    /// Sends a `GET` request to `url` with the given session cookie.
+    fn get(&self, url: &str, session: &str) -> std::io::Result<Response>;
+
+    /// Sends a `POST` request to `url` with the given session cookie and url-encoded form.
+    fn post(&self, url: &str, session: &str, form: &str) -> std::io::Result<Response>;
+}
+
+/// Runs `curl`, which is needed for `https` URLs. The session is passed through its standard input,
+/// so that it doesn't show up in the list of processes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CurlClient;
+
+impl CurlClient {
+    fn send(&self, url: &str, session: &str, form: Option<&str>) -> std::io::Result<Response> {
+        use std::process::{Command, Stdio};
+
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--header", "@-"])
+            .args(["--user-agent", USER_AGENT])
+            .args(["--write-out", "\n%{http_code}"]);
+        if let Some(form) = form {
+            command.args(["--data", form]);
+        }
+        let mut child = command
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(format!("Cookie: session={session}\n").as_bytes())?;
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(std::io::Error::other(format!(
+                "curl failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        let output = String::from_utf8(output.stdout)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+        let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+        let status = status.parse().map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid status code")
+        })?;
+        Ok(Response {
+            status,
+            body: String::from(body),
+        })
+    }
+}
+
+impl HttpClient for CurlClient {
+    fn get(&self, url: &str, session: &str) -> std::io::Result<Response> {
+        self.send(url, session, None)
+    }
+
+    fn post(&self, url: &str, session: &str, form: &str) -> std::io::Result<Response> {
+        self.send(url, session, Some(form))
+    }
+}
+
+/// A minimal HTTP/1.1 client over a TCP connection. It only supports plain `http` URLs, such as the
+/// ones of a local mock server, and responses that are not chunked.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpClient;
+
+impl TcpClient {
+    fn send(&self, url: &str, session: &str, form: Option<&str>) -> std::io::Result<Response> {
+        let invalid = |message: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| invalid("only http URLs are supported"))?;
+        let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let address = if host.contains(':') {
+            String::from(host)
+        } else {
+            format!("{host}:80")
+        };
+        let mut request = format!(
+            "{} {} HTTP/1.1\r\nHost: {host}\r\nUser-Agent: {USER_AGENT}\r\nCookie: session={session}\r\nConnection: close\r\n",
+            if form.is_some() { "POST" } else { "GET" },
+            if path.is_empty() { "/" } else { path },
+        );
+        match form {
+            Some(form) => request += &format!(
+                "Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\r\n{form}",
+                form.len()
+            ),
+            None => request += "\r\n",
+        }
+        let mut stream = std::net::TcpStream::connect(address)?;
+        stream.write_all(request.as_bytes())?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| invalid("incomplete response"))?;
+        let status = head
+            .split(' ')
+            .nth(1)
+            .and_then(|x| x.parse().ok())
+            .ok_or_else(|| invalid("invalid status line"))?;
+        Ok(Response {
+            status,
+            body: String::from(body),
+        })
+    }
+}
+
+impl HttpClient for TcpClient {
+    fn get(&self, url: &str, session: &str) -> std::io::Result<Response> {
+        self.send(url, session, None)
+    }
+
+    fn post(&self, url: &str, session: &str, form: &str) -> std::io::Result<Response> {
+        self.send(url, session, Some(form))
+    }
+}
+
+/// Why a request to the puzzle website failed.
+#[derive(Debug)]
+pub enum HttpError {
+    /// There is no session to make the request with.
+    MissingSession,
+    /// The server did not answer with a success, e.g. because the puzzle is not unlocked yet or
+    /// the session expired.
+    Status(Response),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSession => write!(
+                f,
+                "set {SESSION_VAR} to the session cookie of the puzzle website"
+            ),
+            Self::Status(response) => write!(
+                f,
+                "the server answered with status {}: {}",
+                response.status,
+                response.body.trim()
+            ),
+            Self::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<std::io::Error> for HttpError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// The puzzle website, with the session of the account the requests are made for.
+pub struct Website {
+    base_url: String,
+    session: Option<String>,
+    client: Box<dyn HttpClient>,
+}
+
+impl Website {
+    pub fn new(base_url: &str, session: Option<String>, client: Box<dyn HttpClient>) -> Self {
+        Self {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session,
+            client,
+        }
+    }
+
+    /// Uses the session and website URL set in the environment. `curl` is used for `https` URLs,
+    /// and [`TcpClient`] for `http` ones.
+    pub fn from_env() -> Self {
+        let base_url =
+            std::env::var(BASE_URL_VAR).unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .map(|x| String::from(x.trim()))
+            .filter(|x| !x.is_empty());
+        let client: Box<dyn HttpClient> = if base_url.starts_with("http://") {
+            Box::new(TcpClient)
+        } else {
+            Box::new(CurlClient)
+        };
+        Self::new(&base_url, session, client)
+    }
+
+    /// Whether a session is set, without which no request can be made.
+    pub fn has_session(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// Fetches the page at `path` (such as `/2023/day/1/input`), failing unless it succeeds.
+    pub fn get(&self, path: &str) -> Result<Response, HttpError> {
+        let session = self.session.as_deref().ok_or(HttpError::MissingSession)?;
+        check_status(self.client.get(&(self.base_url.clone() + path), session)?)
+    }
+
+    /// Posts the url-encoded `form` to `path`, failing unless it succeeds.
+    pub fn post(&self, path: &str, form: &str) -> Result<Response, HttpError> {
+        let session = self.session.as_deref().ok_or(HttpError::MissingSession)?;
+        check_status(
+            self.client
+                .post(&(self.base_url.clone() + path), session, form)?,
+        )
+    }
+}
+
+fn check_status(response: Response) -> Result<Response, HttpError> {
+    if response.status == 200 {
+        Ok(response)
+    } else {
+        Err(HttpError::Status(response))
+    }
+}
+
+/// Encodes `s` to be used as a value in a url-encoded form.
+pub fn form_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                String::from(b as char)
+            }
+            b' ' => String::from("+"),
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockServer;
+
+    #[test]
+    fn tcp_client_sends_requests() {
+        let server = MockServer::start(&[("/page", 200, "hello"), ("/form", 200, "posted")]);
+        let website = Website::new(
+            &server.url(),
+            Some(String::from("abc")),
+            Box::new(TcpClient),
+        );
+        assert_eq!(website.get("/page").unwrap().body, "hello");
+        assert_eq!(website.post("/form", "a=1&b=x+y").unwrap().body, "posted");
+        assert!(matches!(
+            website.get("/missing"),
+            Err(HttpError::Status(Response { status: 404, .. }))
+        ));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            (requests[1].method.as_str(), requests[1].path.as_str()),
+            ("POST", "/form")
+        );
+        assert_eq!(requests[1].cookie, "session=abc");
+        assert_eq!(requests[1].body, "a=1&b=x+y");
+
+        let website = Website::new(&server.url(), None, Box::new(TcpClient));
+        assert!(matches!(
+            website.get("/page"),
+            Err(HttpError::MissingSession)
+        ));
+        assert_eq!(form_encode("a b&c=1"), "a+b%26c%3D1");
+    }
+}
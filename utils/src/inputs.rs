@@ -0,0 +1,179 @@
+//! Puzzle inputs kept in the data directory, and downloaded from the puzzle website when missing.
+//!
+//! The data directory acts as the cache: `data/<year>/dayNN/input.txt` is only fetched if it does
+//! not exist yet or is empty (as left by `aoc new`), and is never overwritten once it has content.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::http::{HttpError, Website};
+
+/// The path of the puzzle input of `day`, relative to the data directory of its year.
+pub fn input_path(day: u32) -> String {
+    format!("day{day:02}/input.txt")
+}
+
+/// Whether the input at `path` still has to be fetched: it doesn't exist, or it is an empty
+/// placeholder.
+pub fn is_missing(path: &Path) -> bool {
+    std::fs::metadata(path).map_or(true, |metadata| metadata.len() == 0)
+}
+
+/// Provides the puzzle inputs, from the data directory if they are there, or else by downloading
+/// and storing them.
+pub struct InputProvider {
+    data_dir: PathBuf,
+    website: Website,
+}
+
+impl InputProvider {
+    pub fn new(data_dir: impl Into<PathBuf>, website: Website) -> Self {
+        Self {
+            data_dir: data_dir.into(),
+            website,
+        }
+    }
+
+    /// Uses the workspace data directory, and the website set in the environment.
+    pub fn from_env() -> Self {
+        Self::new(crate::data_dir(), Website::from_env())
+    }
+
+    /// The path of the input of `day` of `year`, whether it is cached or not.
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.data_dir.join(year.to_string()).join(input_path(day))
+    }
+
+    /// Whether missing inputs can be downloaded, i.e. whether a session is set.
+    pub fn can_fetch(&self) -> bool {
+        self.website.has_session()
+    }
+
+    /// Returns the path of the input of `day` of `year`, downloading it first if it is missing.
+    pub fn get(&self, year: u32, day: u32) -> Result<PathBuf, HttpError> {
+        let path = self.path(year, day);
+        if !is_missing(&path) {
+            return Ok(path);
+        }
+        let response = self.website.get(&format!("/{year}/day/{day}/input"))?;
+        store(&path, &response.body)?;
+        Ok(path)
+    }
+}
+
+/// Writes a new file at `path`, readable only by its owner since inputs are personal. Its content
+/// is written to a temporary file first, so that `path` never holds a partial input, and an
+/// existing file is left as is unless it is empty.
+fn store(path: &Path, text: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension(format!("part{}", std::process::id()));
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let result = options
+        .open(&partial)
+        .and_then(|mut file| file.write_all(text.as_bytes()))
+        .and_then(|()| match std::fs::hard_link(&partial, path) {
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {
+                if is_missing(path) {
+                    std::fs::rename(&partial, path)
+                } else {
+                    // Another run stored it first.
+                    Ok(())
+                }
+            }
+            result => result,
+        });
+    std::fs::remove_file(&partial).ok();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{HttpClient, Response, TcpClient, DEFAULT_BASE_URL};
+    use crate::testing::MockServer;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{name}-{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        dir
+    }
+
+    #[test]
+    fn fetches_missing_inputs_once() {
+        let server = MockServer::start(&[
+            ("/2023/day/1/input", 200, "1abc2\n"),
+            ("/2023/day/2/input", 404, "Not unlocked yet"),
+        ]);
+        let dir = temp_dir("fetch");
+        let website = Website::new(
+            &server.url(),
+            Some(String::from("token")),
+            Box::new(TcpClient),
+        );
+        let provider = InputProvider::new(&dir, website);
+
+        let path = provider.get(2023, 1).unwrap();
+        assert_eq!(path, dir.join("2023/day01/input.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1abc2\n");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        assert_eq!(provider.get(2023, 1).unwrap(), path);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            (requests[0].path.as_str(), requests[0].cookie.as_str()),
+            ("/2023/day/1/input", "session=token")
+        );
+
+        assert!(matches!(
+            provider.get(2023, 2),
+            Err(HttpError::Status(Response { status: 404, .. }))
+        ));
+        assert!(!provider.path(2023, 2).exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn never_overwrites_inputs() {
+        struct Unreachable;
+        impl HttpClient for Unreachable {
+            fn get(&self, _url: &str, _session: &str) -> std::io::Result<Response> {
+                panic!("the input should be read from the cache");
+            }
+
+            fn post(&self, _url: &str, _session: &str, _form: &str) -> std::io::Result<Response> {
+                unreachable!()
+            }
+        }
+
+        let dir = temp_dir("cache");
+        let website = Website::new(DEFAULT_BASE_URL, None, Box::new(Unreachable));
+        let provider = InputProvider::new(&dir, website);
+        assert!(matches!(
+            provider.get(2023, 3),
+            Err(HttpError::MissingSession)
+        ));
+
+        let path = provider.path(2023, 3);
+        store(&path, "").unwrap();
+        assert!(is_missing(&path));
+        store(&path, "cached").unwrap();
+        store(&path, "fetched again").unwrap();
+        assert_eq!(provider.get(2023, 3).unwrap(), path);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "cached");
+        assert_eq!(
+            std::fs::read_dir(path.parent().unwrap()).unwrap().count(),
+            1
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,364 @@
+//! Sets of values stored as the intervals they cover, and maps shifting intervals of values.
+//!
+//! Intervals are half-open, like `Range`s, so that `start..end` holds `end - start` values and
+//! adjacent intervals share a bound:
+//!
+//! ```ignore
+//! let seeds: IntervalSet<u64> = [79..93, 55..68].into_iter().collect();
+//! let mut map = RangeMap::new();
+//! map.insert(98..100, 50);
+//! map.insert(50..98, 52);
+//! assert_eq!(map.map(&seeds).ranges(), [57..70, 81..95]);
+//! ```
+//!
+//! A chain of maps can be composed with [`RangeMap::then`] into a single map, which finds where
+//! a value goes with a binary search, and which values go into a set with
+//! [`RangeMap::preimage`].
+
+use std::ops::{Add, Range, Sub};
+
+/// A set of values, stored as the intervals it covers. The intervals are kept sorted, non-empty
+/// and apart from each other, so that sets holding the same values are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The intervals covered by the set, in increasing order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    /// The smallest value of the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// The bound right after the largest value of the set.
+    pub fn end(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end)
+    }
+
+    /// Adds the values of `range`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|x| x.end < range.start);
+        let last = first + self.ranges[first..].partition_point(|x| x.start <= range.end);
+        let merged = if first < last {
+            range.start.min(self.ranges[first].start)..range.end.max(self.ranges[last - 1].end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(x), Some(y)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = x.start.max(y.start);
+            let end = x.end.min(y.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if x.end < y.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut first = 0;
+        for range in &self.ranges {
+            while other
+                .ranges
+                .get(first)
+                .is_some_and(|x| x.end <= range.start)
+            {
+                first += 1;
+            }
+            let mut start = range.start;
+            for removed in other.ranges[first..]
+                .iter()
+                .take_while(|x| x.start < range.end)
+            {
+                if start < removed.start {
+                    ranges.push(start..removed.start);
+                }
+                start = start.max(removed.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + std::iter::Sum> IntervalSet<T> {
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// A piecewise map shifting intervals of values: each source interval is mapped to the interval
+/// of the same length at its destination. Values outside of every source interval map to
+/// themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeMap<T> {
+    /// The source intervals, sorted and disjoint, with where each one starts once mapped.
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self { pieces: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> RangeMap<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the values of `source` to the ones starting at `destination`. The values of `source`
+    /// that are already mapped keep their mapping.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        let unmapped = IntervalSet::from(source.clone()).difference(&self.sources());
+        for range in unmapped.ranges {
+            let idx = self.pieces.partition_point(|(x, _)| x.start < range.start);
+            let start = destination + (range.start - source.start);
+            self.pieces.insert(idx, (range, start));
+        }
+    }
+
+    /// The source intervals, in increasing order, with where each one starts once mapped.
+    pub fn pieces(&self) -> impl Iterator<Item = (Range<T>, T)> + '_ {
+        self.pieces.iter().cloned()
+    }
+
+    /// The values that are shifted by the map.
+    pub fn sources(&self) -> IntervalSet<T> {
+        self.pieces.iter().map(|(range, _)| range.clone()).collect()
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let idx = self.pieces.partition_point(|(x, _)| x.end <= value);
+        match self.pieces.get(idx) {
+            Some((source, destination)) if source.start <= value => {
+                *destination + (value - source.start)
+            }
+            _ => value,
+        }
+    }
+
+    /// Splits `range` where the pieces of the map start and end, returning each part with where it
+    /// starts once mapped. Only the pieces overlapping `range` are visited.
+    pub fn segments(&self, range: Range<T>) -> Vec<(Range<T>, T)> {
+        let mut segments = Vec::new();
+        if range.is_empty() {
+            return segments;
+        }
+        let first = self.pieces.partition_point(|(x, _)| x.end <= range.start);
+        let mut start = range.start;
+        for (source, destination) in self.pieces[first..]
+            .iter()
+            .take_while(|(x, _)| x.start < range.end)
+        {
+            if start < source.start {
+                segments.push((start..source.start, start));
+                start = source.start;
+            }
+            let end = range.end.min(source.end);
+            segments.push((start..end, *destination + (start - source.start)));
+            start = end;
+        }
+        if start < range.end {
+            segments.push((start..range.end, start));
+        }
+        segments
+    }
+
+    /// The image of `set` through the map.
+    pub fn map(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut image = IntervalSet::new();
+        for range in &set.ranges {
+            for (segment, start) in self.segments(range.clone()) {
+                image.insert(start..start + (segment.end - segment.start));
+            }
+        }
+        image
+    }
+
+    /// The values that `set` is the image of, i.e. the ones mapped into `set`.
+    pub fn preimage(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut preimage = set.difference(&self.sources());
+        for (source, destination) in &self.pieces {
+            let image = *destination..*destination + (source.end - source.start);
+            for range in set.intersection(&IntervalSet::from(image)).ranges {
+                preimage.insert(
+                    source.start + (range.start - *destination)
+                        ..source.start + (range.end - *destination),
+                );
+            }
+        }
+        preimage
+    }
+
+    /// The map applying `self`, then `next`, so that a chain of maps can be walked only once.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut composed = Self::new();
+        for (source, destination) in &self.pieces {
+            let image = *destination..*destination + (source.end - source.start);
+            for (segment, start) in next.segments(image) {
+                let from = source.start + (segment.start - *destination);
+                composed.insert(from..from + (segment.end - segment.start), start);
+            }
+        }
+        // The other values are left as is by `self`.
+        for (source, destination) in &next.pieces {
+            composed.insert(source.clone(), *destination);
+        }
+        composed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sets_stay_normalized() {
+        let a: IntervalSet<i32> = [5..8, 0..2, 1..3, 8..10, 4..4].into_iter().collect();
+        assert_eq!(a.ranges(), [0..3, 5..10]);
+        assert_eq!(a.len(), 8);
+        assert!(a.contains(0) && a.contains(9) && !a.contains(3) && !a.contains(10));
+        assert_eq!((a.min(), a.end()), (Some(0), Some(10)));
+
+        let b: IntervalSet<i32> = [2..6, 9..12].into_iter().collect();
+        assert_eq!(a.union(&b), IntervalSet::from(0..12));
+        assert_eq!(a.intersection(&b).ranges(), [2..3, 5..6, 9..10]);
+        assert_eq!(a.difference(&b).ranges(), [0..2, 6..9]);
+        assert_eq!(b.difference(&a).ranges(), [3..5, 10..12]);
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(IntervalSet::new().union(&a), a);
+    }
+
+    #[test]
+    fn range_maps_shift_intervals() {
+        let mut map = RangeMap::new();
+        map.insert(98..100, 50);
+        map.insert(50..98, 52);
+        // Only 100..102 is not mapped yet.
+        map.insert(96..102, 0);
+        assert_eq!(
+            map.pieces().collect::<Vec<_>>(),
+            [(50..98, 52), (98..100, 50), (100..102, 4)]
+        );
+        assert_eq!(
+            [0, 49, 50, 79, 97, 98, 99, 101, 102].map(|x| map.get(x)),
+            [0, 49, 52, 81, 99, 50, 51, 5, 102]
+        );
+
+        let seeds: IntervalSet<u64> = [79..93, 55..68, 95..110].into_iter().collect();
+        assert_eq!(
+            map.map(&seeds).ranges(),
+            [4..6, 50..52, 57..70, 81..95, 97..100, 102..110]
+        );
+    }
+
+    #[test]
+    fn range_maps_compose_and_invert() {
+        let mut first = RangeMap::new();
+        first.insert(0..10, 20);
+        first.insert(10..20, 100);
+        let mut second = RangeMap::new();
+        second.insert(25..35, 0);
+        second.insert(105..108, 5);
+        second.insert(200..210, 300);
+
+        let composed = first.then(&second);
+        for x in 0..250 {
+            assert_eq!(composed.get(x), second.get(first.get(x)), "{x}");
+        }
+        let set: IntervalSet<u64> = [3..12, 15..40, 205..220].into_iter().collect();
+        assert_eq!(composed.map(&set), second.map(&first.map(&set)));
+        assert_eq!(
+            composed.segments(8..22),
+            [
+                (8..10, 3),
+                (10..15, 100),
+                (15..18, 5),
+                (18..20, 108),
+                (20..22, 20)
+            ]
+        );
+
+        // 0..5 comes from 5..10 and 5..8 from 15..18 through both maps, and 0..8 from 25..33 and
+        // 5..8 from 105..108 through `second` only.
+        let preimage = composed.preimage(&IntervalSet::from(0..8));
+        assert_eq!(preimage.ranges(), [5..10, 15..18, 25..33, 105..108]);
+        for x in 0..250 {
+            assert_eq!(preimage.contains(x), composed.get(x) < 8, "{x}");
+        }
+    }
+}
@@ -1,46 +1,684 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub mod answers;
+pub mod bench;
+pub mod grid;
+pub mod http;
+pub mod inputs;
+pub mod interval;
+pub mod output;
+pub mod panic;
+pub mod parse;
+pub mod pool;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod testing;
+pub mod vec;
+
+pub use answers::{Answer, ExpectedAnswers, Verdict};
+pub use bench::{BenchOptions, BenchResult};
+pub use output::{OutputFormat, Record};
+pub use parse::{ParseError, ParseErrorKind, ParseInput};
+pub use solution::{Check, Solution};
+
+/// What the runner shows about an input besides the answers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum PrintMode {
+    #[default]
     None,
+    /// A short summary of the input, such as its dimensions and counts.
+    Summary,
+    /// The summary, then the input in human-readable form (falls back to `Debug` if the input
+    /// doesn't provide one).
+    Display,
+    /// The summary, then the whole `Debug` representation of the input.
     Debug,
+    /// Same as `Display`, and solutions also print their intermediate state with [`trace!`].
+    Trace,
+}
+
+impl std::str::FromStr for PrintMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "summary" => Ok(Self::Summary),
+            "display" => Ok(Self::Display),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
+            _ => Err(format!("Invalid print mode '{s}'")),
+        }
+    }
+}
+
+thread_local! {
+    static PRINT_CONTEXT: std::cell::Cell<(PrintMode, OutputFormat)> =
+        const { std::cell::Cell::new((PrintMode::None, OutputFormat::Text)) };
+}
+
+/// The print mode of the input being solved on this thread, so that solutions can show more
+/// details on demand.
+pub fn print_mode() -> PrintMode {
+    PRINT_CONTEXT.get().0
+}
+
+/// Whether solutions should print their intermediate state.
+pub fn tracing() -> bool {
+    print_mode() == PrintMode::Trace
+}
+
+/// Sets the print mode returned by `print_mode` on this thread until the guard is dropped.
+fn set_print_context(mode: PrintMode, format: OutputFormat) -> impl Drop {
+    struct Guard((PrintMode, OutputFormat));
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            PRINT_CONTEXT.set(self.0);
+        }
+    }
+
+    Guard(PRINT_CONTEXT.replace((mode, format)))
+}
+
+#[doc(hidden)]
+pub fn print_trace(args: std::fmt::Arguments) {
+    output::emit(PRINT_CONTEXT.get().1.message_stream(), args);
+}
+
+/// Prints a line, like `println!`, but only in the `Trace` print mode. It goes to the standard
+/// error with structured output formats, so that it doesn't mix with the records.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::tracing() {
+            $crate::print_trace(format_args!($($arg)*));
+        }
+    };
+}
+
+thread_local! {
+    static CANCELLED: std::cell::RefCell<Option<std::sync::Arc<std::sync::atomic::AtomicBool>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Whether the part being solved on this thread went over its time budget. Its answer is then
+/// discarded, so long searches can check it to stop early instead of running in the background.
+pub fn cancelled() -> bool {
+    CANCELLED.with_borrow(|x| {
+        x.as_ref()
+            .is_some_and(|x| x.load(std::sync::atomic::Ordering::Relaxed))
+    })
+}
+
+/// Where the text of an input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(std::path::PathBuf),
+}
+
+impl InputSource {
+    /// `-` stands for the standard input, relative paths are resolved against the data directory
+    /// of `year` (`data/<year>`), or against the `data` directory itself without one.
+    pub fn from_path(path: &str, year: Option<u32>) -> Self {
+        if path == "-" {
+            Self::Stdin
+        } else {
+            let data_dir = data_dir();
+            match year {
+                Some(year) => Self::File(data_dir.join(year.to_string()).join(path)),
+                None => Self::File(data_dir.join(path)),
+            }
+        }
+    }
+
+    pub fn read_to_string(&self) -> std::io::Result<String> {
+        match self {
+            Self::Stdin => std::io::read_to_string(std::io::stdin().lock()),
+            Self::File(path) => std::fs::read_to_string(path),
+        }
+    }
+}
+
+impl std::fmt::Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::File(path) => write!(f, "{path:?}"),
+        }
+    }
+}
+
+/// Returns the `data` directory of the workspace. It is looked up in the current directory first,
+/// then in its ancestors, so that it is also found when running from a day's crate (as `cargo test`
+/// does).
+pub fn data_dir() -> std::path::PathBuf {
+    let data_dir = std::path::PathBuf::from("data/");
+    if data_dir.is_dir() {
+        return data_dir;
+    }
+    std::env::current_dir()
+        .ok()
+        .and_then(|dir| dir.ancestors().map(|x| x.join("data")).find(|x| x.is_dir()))
+        .unwrap_or(data_dir)
+}
+
+/// Settings shared by all the inputs of a run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Only run the given part (1-based) if specified.
+    pub part: Option<usize>,
+    /// Benchmark the parsing and each part after checking the answers.
+    pub bench: Option<BenchOptions>,
+    pub format: OutputFormat,
+    /// Overrides the print mode of every input if specified.
+    pub print_mode: Option<PrintMode>,
+    /// Year whose data directory the input paths are relative to, filled in by `Day::run`.
+    pub year: Option<u32>,
+    /// Day reported in structured output, filled in by `Day::run`.
+    pub day: Option<u32>,
+    /// Time budget of each part. Parts are then solved on a worker thread, and reported as timed
+    /// out (without stopping the run) if they go over it.
+    pub timeout: Option<std::time::Duration>,
+    /// Solve the parts of each input concurrently, printing their output in the same order as
+    /// when solving them one after the other. When the inputs are run by [`pool::map_in_order`],
+    /// the parts only use the threads it can spare.
+    pub parallel: bool,
+    /// Only check the assumptions of the solutions on each input and print a report, instead of
+    /// solving it. Each check is counted as passed or failed.
+    pub validate: bool,
+}
+
+/// Counts how many answers matched the expected ones after a run.
+/// Inputs that could not be parsed are counted as failures.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    pub passed: usize,
+    pub failed: usize,
+    pub timed_out: usize,
+    /// Results of each benchmarked input, if benchmarking was enabled.
+    pub benchmarks: Vec<BenchResult>,
+    /// The answer of each solved part with its (1-based) number, in the order they were printed.
+    pub answers: Vec<(usize, Answer)>,
+}
+
+impl Report {
+    pub fn is_success(&self) -> bool {
+        self.failed == 0 && self.timed_out == 0
+    }
+}
+
+impl std::ops::AddAssign for Report {
+    fn add_assign(&mut self, rhs: Self) {
+        self.passed += rhs.passed;
+        self.failed += rhs.failed;
+        self.timed_out += rhs.timed_out;
+        self.benchmarks.extend(rhs.benchmarks);
+        self.answers.extend(rhs.answers);
+    }
+}
+
+impl From<Report> for std::process::ExitCode {
+    fn from(report: Report) -> Self {
+        if report.is_success() {
+            Self::SUCCESS
+        } else {
+            Self::FAILURE
+        }
+    }
+}
+
+/// Runs a day on the given inputs.
+pub type Runner = fn(&[(&str, PrintMode)], &RunOptions) -> Report;
+
+/// Describes the solutions of a single day, so that they can be looked up and run from a registry.
+/// Days are identified by their year and number, and their inputs are relative to `data/<year>`.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub number: u32,
+    pub inputs: &'static [(&'static str, PrintMode)],
+    pub runner: Runner,
 }
 
-pub fn run<InputType, SolutionType>(
-    inputs: &[(&str, PrintMode)],
-    solutions: &[fn(&InputType) -> SolutionType],
-) where
-    InputType: From<std::fs::File> + std::fmt::Debug,
-    SolutionType: std::fmt::Debug,
-{
+impl Day {
+    pub fn run(&self, inputs: &[(&str, PrintMode)], options: &RunOptions) -> Report {
+        let options = RunOptions {
+            year: Some(self.year),
+            day: Some(self.number),
+            ..*options
+        };
+        (self.runner)(inputs, &options)
+    }
+
+    /// Runs all parts on the default inputs of the day.
+    pub fn run_all(&self) -> Report {
+        self.run(self.inputs, &RunOptions::default())
+    }
+}
+
+/// Runs both parts of a solution on the given inputs.
+pub fn run<S: Solution>(inputs: &[(&str, PrintMode)]) -> Report {
+    run_with_options::<S>(inputs, &RunOptions::default())
+}
+
+/// Same as `run`, but configured by `options`. With a structured output format, a record is
+/// printed for each answer and any other message goes to the standard error.
+pub fn run_with_options<S: Solution>(inputs: &[(&str, PrintMode)], options: &RunOptions) -> Report {
     use std::time::Instant;
 
+    let messages = options.format.message_stream();
+    macro_rules! log {
+        () => {
+            log!("")
+        };
+        ($($arg:tt)*) => {
+            output::emit(messages, format_args!($($arg)*))
+        };
+    }
+    macro_rules! out {
+        ($($arg:tt)*) => {
+            output::emit(output::Stream::Stdout, format_args!($($arg)*))
+        };
+    }
+
+    let mut report = Report::default();
+
     for (input_path, print_mode) in inputs {
-        let full_path = std::path::PathBuf::from("data/").join(input_path);
-        let input_file =
-            std::fs::File::open(full_path.clone()).expect("Failed to open the input file.");
+        let source = InputSource::from_path(input_path, options.year);
+        let input_text = match read_input(&source, input_path, options) {
+            Ok(input_text) => input_text,
+            Err(error) => {
+                log!("Failed to read input from: {source}");
+                log!("- {error}\n");
+                report.failed += 1;
+                continue;
+            }
+        };
 
         let input_time = Instant::now();
-        let input = InputType::from(input_file);
+        let input = match panic::catch_panic(|| S::parse(&input_text)) {
+            Ok(Ok(input)) => std::sync::Arc::new(input),
+            Ok(Err(error)) => {
+                log!("Failed to parse input from: {source}");
+                log!("{}\n", format_parse_error(&input_text, &error));
+                report.failed += 1;
+                continue;
+            }
+            Err(panic) => {
+                log!("Failed to parse input from: {source}");
+                log!("- Parser {panic}\n");
+                report.failed += 1;
+                continue;
+            }
+        };
         let input_time = input_time.elapsed();
-        println!(
-            "Read input from: {full_path:?} (took {} μs)",
+        log!(
+            "Read input from: {source} (took {} μs)",
             input_time.as_micros()
         );
 
-        if *print_mode == PrintMode::Debug {
-            println!("{input:?}");
+        let print_mode = options.print_mode.unwrap_or(*print_mode);
+        if print_mode != PrintMode::None {
+            if let Some(summary) = input.summary() {
+                log!("{summary}");
+            }
+        }
+        match print_mode {
+            PrintMode::None | PrintMode::Summary => (),
+            PrintMode::Display | PrintMode::Trace => match input.display() {
+                Some(display) => log!("{}", display.trim_end_matches('\n')),
+                None => log!("{input:?}"),
+            },
+            PrintMode::Debug => log!("{input:?}"),
         }
 
-        for (i, solution) in solutions.iter().enumerate() {
-            let time = Instant::now();
-            let answer = solution(&input);
-            let time = time.elapsed();
-            println!(
-                "- Part {} answer: '{answer:?}' (took {} μs)",
-                i + 1,
-                time.as_micros()
+        let checks: Vec<Check> = panic::catch_panic(|| S::validate(&input))
+            .unwrap_or_else(|panic| {
+                vec![Check::new(
+                    &[1, 2],
+                    "the input can be validated",
+                    Err(panic.to_string()),
+                )]
+            })
+            .into_iter()
+            .filter(|check| options.part.is_none_or(|part| check.parts.contains(&part)))
+            .collect();
+        if options.validate {
+            if print_mode == PrintMode::None {
+                if let Some(summary) = input.summary() {
+                    log!("{summary}");
+                }
+            }
+            if checks.is_empty() {
+                log!("- No assumptions to check");
+            }
+            for check in &checks {
+                if check.holds() {
+                    log!("- OK: {check}");
+                    report.passed += 1;
+                } else {
+                    log!("- FAILED: {check}");
+                    report.failed += 1;
+                }
+            }
+            log!();
+            continue;
+        }
+
+        let expected_answers = match read_expected_answers(&source) {
+            Ok(expected_answers) => expected_answers,
+            Err(message) => {
+                log!("{message}");
+                report.failed += 1;
+                ExpectedAnswers::default()
+            }
+        };
+
+        let solutions = S::parts();
+        let selected_solutions = solutions
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| options.part.is_none_or(|part| part == i + 1));
+        // With `parallel`, the parts are all solved first, within the thread limit of the pool
+        // running this input if there is one, and their output is printed in order with their
+        // answer.
+        let mut solved_in_parallel = Vec::new();
+        if options.parallel {
+            pool::map_in_order(
+                selected_solutions.clone().collect(),
+                solutions.len(),
+                |(_, solution)| {
+                    output::capture(|| solve::<S>(&input, *solution, print_mode, options))
+                },
+                |solved| solved_in_parallel.push(solved),
             );
         }
+        let mut solved_in_parallel = solved_in_parallel.into_iter();
+
+        // Parts that panicked or timed out, which are not benchmarked.
+        let mut unsolved_parts = Vec::new();
+        for (i, solution) in selected_solutions.clone() {
+            for check in checks
+                .iter()
+                .filter(|x| !x.holds() && x.parts.contains(&(i + 1)))
+            {
+                log!(
+                    "- Part {} WARNING: {} does not hold ({})",
+                    i + 1,
+                    check.description,
+                    check.failure.as_deref().unwrap_or_default()
+                );
+            }
+            let solved = match solved_in_parallel.next() {
+                Some((solved, captured)) => {
+                    captured.print();
+                    solved
+                }
+                None => solve::<S>(&input, *solution, print_mode, options),
+            };
+            let (answer, time, verdict) = match solved {
+                Some((Ok(answer), time)) => {
+                    let verdict = Verdict::new(expected_answers.get(i + 1), &answer);
+                    (answer, time, verdict)
+                }
+                Some((Err(panic), time)) => {
+                    unsolved_parts.push(i);
+                    (Answer::None, time, Verdict::Panicked(panic))
+                }
+                None => {
+                    unsolved_parts.push(i);
+                    (Answer::None, options.timeout.unwrap(), Verdict::Timeout)
+                }
+            };
+            let record = Record {
+                year: options.year,
+                day: options.day,
+                part: i + 1,
+                input: input_path,
+                answer: &answer,
+                verdict: &verdict,
+                parse_time: input_time,
+                solve_time: time,
+            };
+            match options.format.format(&record) {
+                Some(line) => out!("{line}"),
+                None if verdict == Verdict::Timeout => out!(
+                    "- Part {} {verdict} (no answer after {})",
+                    i + 1,
+                    bench::format_duration(time)
+                ),
+                None if matches!(verdict, Verdict::Panicked(_)) => {
+                    out!("- Part {} {verdict}", i + 1)
+                }
+                None => out!(
+                    "- Part {} answer: '{answer}' (took {} μs){}",
+                    i + 1,
+                    time.as_micros(),
+                    match verdict {
+                        Verdict::Unchecked => String::new(),
+                        _ => format!(" {verdict}"),
+                    }
+                ),
+            }
+            match verdict {
+                Verdict::Unchecked => (),
+                Verdict::Pass => report.passed += 1,
+                Verdict::Fail { .. } | Verdict::Panicked(_) => report.failed += 1,
+                Verdict::Timeout => report.timed_out += 1,
+            }
+            if !unsolved_parts.contains(&i) {
+                report.answers.push((i + 1, answer));
+            }
+        }
+
+        if let Some(bench) = &options.bench {
+            let parse = bench.measure(|| S::parse(&input_text));
+            log!("- Parsing: {parse}");
+            let mut parts = Vec::new();
+            for (i, solution) in selected_solutions.filter(|(i, _)| !unsolved_parts.contains(i)) {
+                let stats = bench.measure(|| solution(&input));
+                log!("- Part {}: {stats}", i + 1);
+                parts.push((i + 1, stats));
+            }
+            report.benchmarks.push(BenchResult {
+                input: String::from(*input_path),
+                parse,
+                parts,
+            });
+        }
+
+        log!();
+    }
+
+    report
+}
+
+/// Solves a part, timing it and catching any panic. If there is a time limit, the part is solved
+/// on a worker thread and `None` is returned when it goes over it: the thread is then told to stop
+/// with `cancelled`, and left to finish in the background.
+fn solve<S: Solution>(
+    input: &std::sync::Arc<S::Input>,
+    solution: solution::Part<S>,
+    print_mode: PrintMode,
+    options: &RunOptions,
+) -> Option<(Result<Answer, panic::Panic>, std::time::Duration)> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{mpsc, Arc};
+    use std::time::Instant;
+
+    let format = options.format;
+    let Some(timeout) = options.timeout else {
+        let _print_context = set_print_context(print_mode, format);
+        let time = Instant::now();
+        let answer = panic::catch_panic(|| solution(input));
+        return Some((answer.map(Into::into), time.elapsed()));
+    };
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let capturing = output::is_capturing();
+    let (sender, receiver) = mpsc::channel();
+    {
+        let input = Arc::clone(input);
+        let cancelled = Arc::clone(&cancelled);
+        std::thread::spawn(move || {
+            let _print_context = set_print_context(print_mode, format);
+            CANCELLED.set(Some(cancelled));
+            let solve = || {
+                let time = Instant::now();
+                let answer = panic::catch_panic(|| solution(&input));
+                (answer.map(Into::into), time.elapsed())
+            };
+            // Forward the output to the capture of the calling thread.
+            let (solved, captured) = if capturing {
+                output::capture(solve)
+            } else {
+                (solve(), output::Captured::default())
+            };
+            // The runner stopped waiting if the part timed out.
+            let _ = sender.send((solved, captured));
+        });
+    }
+    match receiver.recv_timeout(timeout) {
+        Ok((solved, captured)) => {
+            captured.print();
+            Some(solved)
+        }
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            cancelled.store(true, Ordering::Relaxed);
+            None
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            unreachable!("the worker sends the outcome of the part before exiting")
+        }
+    }
+}
+
+/// Reads the sidecar answers file of `source`, if there is one. Fails with a message to report
+/// if the file is malformed.
+fn read_expected_answers(source: &InputSource) -> Result<ExpectedAnswers, String> {
+    let Some(path) = ExpectedAnswers::path(source) else {
+        return Ok(ExpectedAnswers::default());
+    };
+    let Ok(text) = std::fs::read_to_string(&path) else {
+        return Ok(ExpectedAnswers::default());
+    };
+    ExpectedAnswers::parse(&text).map_err(|error| {
+        format!(
+            "Failed to parse expected answers from: {path:?}\n{}",
+            format_parse_error(&text, &error)
+        )
+    })
+}
+
+/// Reads the text of an input. The puzzle input of the day is fetched with the
+/// [`InputProvider`](inputs::InputProvider) if it is missing, or if it is an empty placeholder and
+/// a session is set. A placeholder that cannot be fetched is read as is.
+fn read_input(
+    source: &InputSource,
+    input_path: &str,
+    options: &RunOptions,
+) -> Result<String, String> {
+    let text = source.read_to_string();
+    let (Some(year), Some(day)) = (options.year, options.day) else {
+        return text.map_err(|error| error.to_string());
+    };
+    if input_path != inputs::input_path(day) {
+        return text.map_err(|error| error.to_string());
+    }
+    let provider = inputs::InputProvider::from_env();
+    match text {
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            let path = provider.get(year, day).map_err(|error| {
+                format!("The input is missing and could not be fetched: {error}")
+            })?;
+            std::fs::read_to_string(path).map_err(|error| error.to_string())
+        }
+        Ok(text) if text.is_empty() && provider.can_fetch() => Ok(provider
+            .get(year, day)
+            .ok()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .unwrap_or(text)),
+        text => text.map_err(|error| error.to_string()),
+    }
+}
+
+/// Describes `error`, followed by the offending line with a caret under the error position.
+fn format_parse_error(input_text: &str, error: &ParseError) -> String {
+    let mut ans = format!("- Error at {error}");
+    if let Some(line) = input_text.lines().nth(error.line.saturating_sub(1)) {
+        let prefix = format!("  {} | ", error.line);
+        ans += &format!("\n{prefix}{line}");
+        ans += &format!(
+            "\n{}^",
+            " ".repeat(prefix.chars().count() + error.column - 1)
+        );
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
 
-        println!("");
+    #[derive(Debug)]
+    struct Text(String);
+
+    impl ParseInput for Text {
+        fn parse(s: &str) -> Result<Self, ParseError> {
+            Ok(Self(String::from(s.trim())))
+        }
+    }
+
+    /// Part 1 runs until it is cancelled, and part 2 answers right away.
+    struct Slow;
+
+    impl Solution for Slow {
+        type Input = Text;
+        type Answer = Option<usize>;
+
+        fn part_1(_input: &Text) -> Option<usize> {
+            let start = Instant::now();
+            while !cancelled() && start.elapsed() < Duration::from_secs(5) {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            None
+        }
+
+        fn part_2(input: &Text) -> Option<usize> {
+            Some(input.0.len())
+        }
+    }
+
+    #[test]
+    fn parts_over_the_timeout_are_reported() {
+        let dir = std::env::temp_dir().join(format!("aoc-timeout-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        std::fs::write(&path, "hello\n").unwrap();
+        std::fs::write(dir.join("input.answers"), "2: 5\n").unwrap();
+
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..RunOptions::default()
+        };
+        let inputs = [(path.to_str().unwrap(), PrintMode::None)];
+        let (report, captured) = output::capture(|| run_with_options::<Slow>(&inputs, &options));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!((report.passed, report.failed, report.timed_out), (1, 0, 1));
+        assert_eq!(report.answers, [(2, Answer::Int(5))]);
+        let lines: Vec<&str> = captured.lines().collect();
+        assert!(
+            lines.contains(&"- Part 1 TIMEOUT (no answer after 50.00 ms)"),
+            "{lines:?}"
+        );
+        assert!(
+            lines
+                .iter()
+                .any(|x| x.starts_with("- Part 2 answer: '5'") && x.ends_with("PASS")),
+            "{lines:?}"
+        );
     }
 }
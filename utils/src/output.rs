@@ -0,0 +1,270 @@
+use crate::{Answer, Verdict};
+use std::cell::RefCell;
+use std::time::Duration;
+
+/// How the results of a run are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// One JSON object per answer.
+    JsonLines,
+    /// One CSV row per answer, after the header returned by [`OutputFormat::header`].
+    Csv,
+}
+
+impl OutputFormat {
+    pub fn is_text(&self) -> bool {
+        *self == Self::Text
+    }
+
+    /// Where messages other than the records go, so that they don't mix with them.
+    pub fn message_stream(&self) -> Stream {
+        if self.is_text() {
+            Stream::Stdout
+        } else {
+            Stream::Stderr
+        }
+    }
+
+    /// The line to print once before any record.
+    pub fn header(&self) -> Option<&'static str> {
+        match self {
+            Self::Csv => Some("year,day,part,input,answer,verdict,parse_time_ns,solve_time_ns"),
+            _ => None,
+        }
+    }
+
+    /// Formats a record as a single line, or `None` for the text format.
+    pub fn format(&self, record: &Record) -> Option<String> {
+        match self {
+            Self::Text => None,
+            Self::JsonLines => Some(record.to_json()),
+            Self::Csv => Some(record.to_csv()),
+        }
+    }
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::JsonLines),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("Invalid output format '{s}'")),
+        }
+    }
+}
+
+/// The result of running one part on one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    pub part: usize,
+    /// The input path, as given to the runner.
+    pub input: &'a str,
+    pub answer: &'a Answer,
+    pub verdict: &'a Verdict,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl Record<'_> {
+    fn verdict_name(&self) -> &'static str {
+        match self.verdict {
+            Verdict::Unchecked => "unchecked",
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Timeout => "timeout",
+            Verdict::Panicked(_) => "panicked",
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let [year, day] = [self.year, self.day]
+            .map(|x| x.map_or_else(|| String::from("null"), |x| x.to_string()));
+        let details = match self.verdict {
+            Verdict::Fail { expected } => format!(",\"expected\":{}", json_string(expected)),
+            Verdict::Panicked(panic) => format!(
+                ",\"message\":{},\"location\":{}",
+                json_string(&panic.message),
+                panic
+                    .location
+                    .as_deref()
+                    .map_or_else(|| String::from("null"), json_string)
+            ),
+            _ => String::new(),
+        };
+        format!(
+            "{{\"year\":{year},\"day\":{day},\"part\":{},\"input\":{},\"answer\":{},\"verdict\":\"{}\"{details},\"parse_time_ns\":{},\"solve_time_ns\":{}}}",
+            self.part,
+            json_string(self.input),
+            json_value(self.answer),
+            self.verdict_name(),
+            self.parse_time.as_nanos(),
+            self.solve_time.as_nanos()
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let [year, day] =
+            [self.year, self.day].map(|x| x.map_or_else(String::new, |x| x.to_string()));
+        format!(
+            "{year},{day},{},{},{},{},{},{}",
+            self.part,
+            csv_field(self.input),
+            csv_field(&self.answer.to_string()),
+            self.verdict_name(),
+            self.parse_time.as_nanos(),
+            self.solve_time.as_nanos()
+        )
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut ans = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => ans.push_str("\\\""),
+            '\\' => ans.push_str("\\\\"),
+            '\n' => ans.push_str("\\n"),
+            '\r' => ans.push_str("\\r"),
+            '\t' => ans.push_str("\\t"),
+            c if c.is_control() => ans.push_str(&format!("\\u{:04x}", c as u32)),
+            c => ans.push(c),
+        }
+    }
+    ans.push('"');
+    ans
+}
+
+/// Integers become JSON numbers, lists arrays and missing answers `null`.
+fn json_value(answer: &Answer) -> String {
+    match answer {
+        Answer::None => String::from("null"),
+        Answer::Int(x) => x.to_string(),
+        Answer::BigInt(x) => x.to_string(),
+        Answer::Text(x) => json_string(x),
+        Answer::Multi(answers) => {
+            let values: Vec<String> = answers.iter().map(json_value).collect();
+            format!("[{}]", values.join(","))
+        }
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        String::from(s)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Lines emitted while capturing the output, to print them later in a deterministic order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Captured(Vec<(Stream, String)>);
+
+impl Captured {
+    /// The lines, whichever stream they are for.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(_, line)| line.as_str())
+    }
+
+    /// Prints the lines, or adds them to the enclosing capture if there is one.
+    pub fn print(self) {
+        for (stream, line) in self.0 {
+            emit(stream, format_args!("{line}"));
+        }
+    }
+}
+
+thread_local! {
+    static CAPTURE: RefCell<Option<Captured>> = const { RefCell::new(None) };
+}
+
+/// Prints a line to `stream`, or adds it to the output being captured on this thread.
+pub fn emit(stream: Stream, args: std::fmt::Arguments) {
+    CAPTURE.with_borrow_mut(|capture| match capture {
+        Some(captured) => captured.0.push((stream, args.to_string())),
+        None if stream == Stream::Stdout => println!("{args}"),
+        None => eprintln!("{args}"),
+    });
+}
+
+pub fn is_capturing() -> bool {
+    CAPTURE.with_borrow(|x| x.is_some())
+}
+
+/// Calls `f`, returning the lines it emitted on this thread instead of printing them.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Captured) {
+    struct Guard(Option<Captured>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            CAPTURE.set(self.0.take());
+        }
+    }
+
+    let outer = Guard(CAPTURE.replace(Some(Captured::default())));
+    let ans = f();
+    let captured = CAPTURE.take().unwrap_or_default();
+    drop(outer);
+    (ans, captured)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(json_string(r"C:\data"), r#""C:\\data""#);
+        assert_eq!(json_string("a\nb\r\tc"), r#""a\nb\r\tc""#);
+        assert_eq!(json_string("\u{0}\u{1b}\u{7f}"), r#""\u0000\u001b\u007f""#);
+        assert_eq!(json_string("día 1 → ★"), "\"día 1 → ★\"");
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("day01/input.txt"), "day01/input.txt");
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field(r#"say "hi""#), r#""say ""hi""""#);
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+        assert_eq!(csv_field("a\rb"), "\"a\rb\"");
+        assert_eq!(csv_field("★"), "★");
+    }
+
+    #[test]
+    fn records_are_formatted() {
+        let answer = Answer::Text(String::from("A,\"B\""));
+        let verdict = Verdict::Fail {
+            expected: String::from("x\ny"),
+        };
+        let record = Record {
+            year: Some(2023),
+            day: None,
+            part: 2,
+            input: "-",
+            answer: &answer,
+            verdict: &verdict,
+            parse_time: Duration::from_nanos(5),
+            solve_time: Duration::from_micros(1),
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2023,"day":null,"part":2,"input":"-","answer":"A,\"B\"","verdict":"fail","expected":"x\ny","parse_time_ns":5,"solve_time_ns":1000}"#
+        );
+        assert_eq!(record.to_csv(), r#"2023,,2,-,"A,""B""",fail,5,1000"#);
+    }
+}
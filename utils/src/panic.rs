@@ -0,0 +1,81 @@
+use std::cell::{Cell, RefCell};
+
+/// A panic caught by [`catch_panic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Where the panic happened, as `file:line:column`.
+    pub location: Option<String>,
+}
+
+impl std::fmt::Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+/// Calls `f`, turning a panic into an error instead of unwinding further. The panic message is not
+/// printed, since the caller reports it.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    static HOOK: std::sync::Once = std::sync::Once::new();
+    HOOK.call_once(|| {
+        // The location is only known to the hook, so it records the panics of the threads that
+        // are catching them and leaves the others to the default hook.
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                CAUGHT.set(Some(Panic {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(|x| x.to_string()),
+                }));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING.replace(true);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+    result.map_err(|payload| {
+        CAUGHT.take().unwrap_or_else(|| Panic {
+            message: payload_message(&*payload),
+            location: None,
+        })
+    })
+}
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Box<dyn Any>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches_message_and_location() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        let panic = catch_panic(|| panic!("bad input {}", 7)).unwrap_err();
+        assert_eq!(panic.message, "bad input 7");
+        assert!(panic.location.unwrap().starts_with("utils/src/panic.rs:"));
+        let panic = catch_panic(|| "x".parse::<u32>().unwrap()).unwrap_err();
+        assert!(panic
+            .to_string()
+            .contains("called `Result::unwrap()` on an `Err` value"));
+    }
+}
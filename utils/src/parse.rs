@@ -0,0 +1,352 @@
+/// The reason why a piece of input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    UnexpectedEnd,
+    UnexpectedChar(char),
+    MissingDelimiter(String),
+    InvalidValue(String),
+    Io(String),
+    Other(String),
+}
+
+impl std::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Self::UnexpectedChar(c) => write!(f, "unexpected character '{c}'"),
+            Self::MissingDelimiter(delimiter) => write!(f, "expected '{delimiter}'"),
+            Self::InvalidValue(value) => write!(f, "invalid value '{value}'"),
+            Self::Io(message) => write!(f, "failed to read input ({message})"),
+            Self::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// A parsing failure, with the (1-based) line and column where it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, kind: ParseErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Types that can be built from the text of a puzzle input, and shown by the runner.
+pub trait ParseInput: Sized {
+    fn parse(s: &str) -> Result<Self, ParseError>;
+
+    /// Reads the whole input from `reader` (a file, the standard input, an in-memory buffer...)
+    /// and parses it.
+    fn parse_reader<R>(mut reader: R) -> Result<Self, ParseError>
+    where
+        R: std::io::BufRead,
+    {
+        let mut s = String::new();
+        let mut line = 1;
+        loop {
+            match reader.read_line(&mut s) {
+                Ok(0) => break,
+                Ok(_) => line += 1,
+                Err(error) => {
+                    return Err(ParseError::new(
+                        line,
+                        1,
+                        ParseErrorKind::Io(error.to_string()),
+                    ))
+                }
+            }
+        }
+        Self::parse(&s)
+    }
+
+    /// A short description of the input (dimensions, counts...), shown from the `Summary` print
+    /// mode up.
+    fn summary(&self) -> Option<String> {
+        None
+    }
+
+    /// The input in human-readable form (e.g. a grid drawn as text), shown in the `Display` and
+    /// `Trace` print modes.
+    fn display(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A single line of the input, which remembers its position to produce meaningful errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Returns the column at which `s` starts. `s` must be a slice of the line text, which is
+    /// checked in debug builds: otherwise the start of the line is returned.
+    pub fn column(&self, s: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (s.as_ptr() as usize).wrapping_sub(start);
+        debug_assert!(
+            offset <= self.text.len() && s.len() <= self.text.len() - offset,
+            "{s:?} is not a slice of line {} ({:?})",
+            self.number,
+            self.text
+        );
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    pub fn error(&self, s: &str, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.number, self.column(s), kind)
+    }
+
+    /// Returns an error pointing at the end of `s`.
+    pub fn error_after(&self, s: &str, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.number, self.column(s) + s.chars().count(), kind)
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter).ok_or_else(|| {
+            self.error_after(s, ParseErrorKind::MissingDelimiter(String::from(delimiter)))
+        })
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, ParseErrorKind::MissingDelimiter(String::from(prefix))))
+    }
+
+    pub fn strip_suffix(&self, s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        s.strip_suffix(suffix).ok_or_else(|| {
+            self.error_after(s, ParseErrorKind::MissingDelimiter(String::from(suffix)))
+        })
+    }
+
+    pub fn parse<T>(&self, s: &'a str) -> Result<T, ParseError>
+    where
+        T: std::str::FromStr,
+    {
+        s.parse::<T>()
+            .map_err(|_| self.error(s, ParseErrorKind::InvalidValue(String::from(s))))
+    }
+
+    /// Parses each whitespace separated value in `s`.
+    pub fn parse_whitespace_separated<T>(&self, s: &'a str) -> Result<Vec<T>, ParseError>
+    where
+        T: std::str::FromStr,
+    {
+        s.split_whitespace().map(|x| self.parse(x)).collect()
+    }
+
+    /// Parses each value in `s` separated by `delimiter`.
+    pub fn parse_separated<T>(&self, s: &'a str, delimiter: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: std::str::FromStr,
+    {
+        s.split(delimiter).map(|x| self.parse(x)).collect()
+    }
+
+    /// Fails if the line doesn't have exactly `width` characters, as expected for grid rows.
+    pub fn check_width(&self, width: usize) -> Result<(), ParseError> {
+        let len = self.text.chars().count();
+        if len == width {
+            Ok(())
+        } else {
+            Err(ParseError::new(
+                self.number,
+                len.min(width) + 1,
+                ParseErrorKind::Other(format!("expected {width} columns, found {len}")),
+            ))
+        }
+    }
+
+    /// Converts each character in `s` to a `T`.
+    pub fn parse_chars<T>(&self, s: &'a str) -> Result<Vec<T>, ParseError>
+    where
+        T: TryFrom<char, Error = ParseErrorKind>,
+    {
+        let first_column = self.column(s);
+        s.chars()
+            .enumerate()
+            .map(|(idx, c)| {
+                T::try_from(c)
+                    .map_err(|kind| ParseError::new(self.number, first_column + idx, kind))
+            })
+            .collect()
+    }
+}
+
+/// Iterates over the lines of the input, keeping track of line numbers.
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+    count: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(s: &'a str) -> Self {
+        Self {
+            lines: s.lines(),
+            count: 0,
+        }
+    }
+
+    /// Same as `next`, but fails if there are no more lines.
+    pub fn next_line(&mut self) -> Result<Line<'a>, ParseError> {
+        let number = self.count + 1;
+        self.next()
+            .ok_or_else(|| ParseError::new(number, 1, ParseErrorKind::UnexpectedEnd))
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.count += 1;
+        Some(Line {
+            number: self.count,
+            text,
+        })
+    }
+}
+
+pub fn lines(s: &str) -> Lines<'_> {
+    Lines::new(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_position(error: ParseError) -> (usize, usize) {
+        (error.line, error.column)
+    }
+
+    #[derive(Debug)]
+    struct Digit;
+
+    impl TryFrom<char> for Digit {
+        type Error = ParseErrorKind;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            c.is_ascii_digit()
+                .then_some(Digit)
+                .ok_or(ParseErrorKind::UnexpectedChar(c))
+        }
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_text() {
+        let mut lines = lines("first\nGame 12: 3 blue, 4 red");
+        lines.next_line().unwrap();
+        let line = lines.next_line().unwrap();
+        assert_eq!(line.number, 2);
+
+        let (game, draws) = line.split_once(line.text, ": ").unwrap();
+        assert_eq!((game, line.column(draws)), ("Game 12", 10));
+        let error = line.split_once(draws, "; ").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::MissingDelimiter(String::from("; "))
+        );
+        assert_eq!(error_position(error), (2, 23));
+
+        let id = line.strip_prefix(game, "Game ").unwrap();
+        assert_eq!(line.column(id), 6);
+        assert_eq!(line.parse::<u32>(id), Ok(12));
+        assert_eq!(
+            error_position(line.strip_prefix(draws, "4").unwrap_err()),
+            (2, 10)
+        );
+        assert_eq!(
+            error_position(line.strip_suffix(draws, "blue").unwrap_err()),
+            (2, 23)
+        );
+        assert_eq!(line.strip_suffix(draws, " red"), Ok("3 blue, 4"));
+
+        let (_, color) = line.split_once(draws, " ").unwrap();
+        let error = line.parse::<u32>(color).unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::InvalidValue(String::from(color))
+        );
+        assert_eq!(error_position(error), (2, 12));
+        assert_eq!(
+            lines.next_line().unwrap_err(),
+            ParseError::new(3, 1, ParseErrorKind::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let line = lines("é → 12x").next_line().unwrap();
+        let (_, value) = line.split_once(line.text, "→ ").unwrap();
+        assert_eq!(line.column(value), 5);
+        assert_eq!(
+            error_position(line.parse::<u32>(value).unwrap_err()),
+            (1, 5)
+        );
+        assert_eq!(
+            error_position(line.error_after(value, ParseErrorKind::UnexpectedEnd)),
+            (1, 8)
+        );
+        let error = line.parse_chars::<Digit>(value).unwrap_err();
+        assert_eq!(error_position(error), (1, 7));
+    }
+
+    /// The lengths of the lines of an input.
+    #[derive(Debug, PartialEq)]
+    struct Lengths(Vec<usize>);
+
+    impl ParseInput for Lengths {
+        fn parse(s: &str) -> Result<Self, ParseError> {
+            Ok(Self(s.lines().map(str::len).collect()))
+        }
+    }
+
+    #[test]
+    fn inputs_can_be_read_from_readers() {
+        let reader = std::io::Cursor::new("abc\n\nde\n");
+        assert_eq!(Lengths::parse_reader(reader), Ok(Lengths(vec![3, 0, 2])));
+        assert_eq!(Lengths::parse_reader(std::io::empty()), Ok(Lengths(vec![])));
+
+        // Invalid UTF-8 on the second line.
+        let reader = std::io::Cursor::new(b"abc\nd\xffe\n".to_vec());
+        let error = Lengths::parse_reader(reader).unwrap_err();
+        assert_eq!(error_position(error.clone()), (2, 1));
+        assert!(matches!(error.kind, ParseErrorKind::Io(_)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "is not a slice of line 1")]
+    fn columns_of_other_text_are_rejected() {
+        let line = lines("abc").next_line().unwrap();
+        line.column(&String::from("b"));
+    }
+}
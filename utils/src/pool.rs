@@ -0,0 +1,130 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+
+thread_local! {
+    /// How many more threads the pool this thread works for can start, so that calls nested in
+    /// its items stay within its limit.
+    static SPARE_THREADS: RefCell<Option<Arc<AtomicUsize>>> = const { RefCell::new(None) };
+}
+
+/// Takes up to `wanted` threads from `spare`, returning how many were taken.
+fn take_threads(spare: &AtomicUsize, wanted: usize) -> usize {
+    let available = spare
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| {
+            Some(x - x.min(wanted))
+        })
+        .unwrap();
+    available.min(wanted)
+}
+
+/// Calls `f` on every item using up to `threads` worker threads. The results are passed to
+/// `consume` on the calling thread as soon as all the previous ones have been, so that they are
+/// handled in the order of the items whichever finishes first.
+///
+/// When called from an item of another call, the threads are shared with it instead: the item's
+/// thread is reused while it waits, along with the threads the other call no longer needs.
+pub fn map_in_order<T, R>(
+    items: Vec<T>,
+    threads: usize,
+    f: impl Fn(T) -> R + Sync,
+    mut consume: impl FnMut(R),
+) where
+    T: Send,
+    R: Send,
+{
+    let wanted = threads.clamp(1, items.len().max(1));
+    let (spare, threads, nested) = match SPARE_THREADS.with_borrow(Clone::clone) {
+        Some(spare) => {
+            let threads = 1 + take_threads(&spare, wanted - 1);
+            (spare, threads, true)
+        }
+        None => (
+            Arc::new(AtomicUsize::new(threads.max(1) - wanted)),
+            wanted,
+            false,
+        ),
+    };
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+    std::thread::scope(|scope| {
+        for i in 0..threads {
+            let sender = sender.clone();
+            let (queue, f, spare) = (&queue, &f, Arc::clone(&spare));
+            scope.spawn(move || {
+                SPARE_THREADS.set(Some(Arc::clone(&spare)));
+                loop {
+                    let Some((idx, item)) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    if sender.send((idx, f(item))).is_err() {
+                        break;
+                    }
+                }
+                // The thread of the calling item is given back when the call returns instead.
+                if !(nested && i == 0) {
+                    spare.fetch_add(1, Ordering::SeqCst);
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+        for (idx, result) in receiver {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&next) {
+                consume(result);
+                next += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_are_consumed_in_order() {
+        let mut results = Vec::new();
+        map_in_order(
+            (0..20).collect(),
+            4,
+            |x: u64| {
+                std::thread::sleep(std::time::Duration::from_millis((20 - x) % 7));
+                x * x
+            },
+            |x| results.push(x),
+        );
+        assert_eq!(results, (0..20).map(|x| x * x).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn nested_calls_share_the_threads() {
+        let running = AtomicUsize::new(0);
+        let max_running = AtomicUsize::new(0);
+        let work = |x: u64| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            max_running.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+            x
+        };
+        let mut sums = Vec::new();
+        map_in_order(
+            (0..4).collect(),
+            3,
+            |x: u64| {
+                let mut sum = 0;
+                map_in_order((0..6).collect(), 6, work, |y| sum += x * y);
+                sum
+            },
+            |x| sums.push(x),
+        );
+        assert_eq!(sums, [0, 15, 30, 45]);
+        assert!(max_running.load(Ordering::SeqCst) <= 3);
+        assert!(max_running.load(Ordering::SeqCst) >= 2);
+    }
+}
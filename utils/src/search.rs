@@ -0,0 +1,267 @@
+//! Shortest paths over any state space, given the successors of each state.
+//!
+//! States only need to be hashable, so that anything from a position to a position with a heading
+//! and a step count can be searched. The searches reaching a goal return the cheapest path to it:
+//!
+//! ```ignore
+//! let path = search::dijkstra(
+//!     start,
+//!     |pos| grid.neighbours4(*pos).map(|next| (next, grid[next])).collect::<Vec<_>>(),
+//!     |pos| *pos == goal,
+//! )?;
+//! println!("{} steps, cost {}", path.states.len() - 1, path.cost);
+//! ```
+//!
+//! The searches stop early when the part they run in is [`cancelled`](crate::cancelled): the ones
+//! looking for a goal find none, and [`bfs_within`] returns the states reached so far.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest way found from the start to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// The states along the path, from the start to the goal (both included).
+    pub states: Vec<S>,
+}
+
+/// A reached state, with its cost and the index of the state it was reached from.
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parent: Option<usize>,
+}
+
+fn reconstruct<S: Clone, C: Copy>(nodes: &[Node<S, C>], goal: usize) -> Path<S, C> {
+    let mut states = Vec::new();
+    let mut idx = Some(goal);
+    while let Some(i) = idx {
+        states.push(nodes[i].state.clone());
+        idx = nodes[i].parent;
+    }
+    states.reverse();
+    Path {
+        cost: nodes[goal].cost,
+        states,
+    }
+}
+
+/// Finds the cheapest path from `start` to a state for which `is_goal` holds, where `successors`
+/// returns the states reachable from a state with the cost of each move. Costs must not be
+/// negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Same as [`dijkstra`], exploring first the states for which the cost so far plus `heuristic`
+/// is the lowest. The path is the cheapest one as long as `heuristic` never overestimates the
+/// remaining cost to a goal.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(&start), C::default(), 0)));
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![Node {
+        state: start,
+        cost: C::default(),
+        parent: None,
+    }];
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        if crate::cancelled() {
+            return None;
+        }
+        // The state was reached again more cheaply since this entry was pushed.
+        if cost > nodes[idx].cost {
+            continue;
+        }
+        if is_goal(&nodes[idx].state) {
+            return Some(reconstruct(&nodes, idx));
+        }
+        for (next, step_cost) in successors(&nodes[idx].state) {
+            let next_cost = cost + step_cost;
+            let next_idx = match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        cost: next_cost,
+                        parent: Some(idx),
+                    });
+                    *entry.insert(nodes.len() - 1)
+                }
+                Entry::Occupied(entry) => {
+                    let node = &mut nodes[*entry.get()];
+                    if next_cost >= node.cost {
+                        continue;
+                    }
+                    node.cost = next_cost;
+                    node.parent = Some(idx);
+                    *entry.get()
+                }
+            };
+            let estimate = next_cost + heuristic(&nodes[next_idx].state);
+            heap.push(Reverse((estimate, next_cost, next_idx)));
+        }
+    }
+    None
+}
+
+/// Finds a path from `start` to a state for which `is_goal` holds with the fewest moves, where
+/// `successors` returns the states reachable in one move from a state.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut queue = VecDeque::from([0]);
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![Node {
+        state: start,
+        cost: 0,
+        parent: None,
+    }];
+
+    while let Some(idx) = queue.pop_front() {
+        if crate::cancelled() {
+            return None;
+        }
+        if is_goal(&nodes[idx].state) {
+            return Some(reconstruct(&nodes, idx));
+        }
+        for next in successors(&nodes[idx].state) {
+            if let Entry::Vacant(entry) = indices.entry(next) {
+                nodes.push(Node {
+                    state: entry.key().clone(),
+                    cost: nodes[idx].cost + 1,
+                    parent: Some(idx),
+                });
+                entry.insert(nodes.len() - 1);
+                queue.push_back(nodes.len() - 1);
+            }
+        }
+    }
+    None
+}
+
+/// Returns every state reachable from `start` in at most `max_moves` moves, with the fewest moves
+/// needed to reach it.
+pub fn bfs_within<S, I>(
+    start: S,
+    max_moves: usize,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut queue = VecDeque::from([(start.clone(), 0)]);
+    let mut moves = HashMap::from([(start, 0)]);
+    while let Some((state, count)) = queue.pop_front() {
+        if crate::cancelled() {
+            break;
+        }
+        if count == max_moves {
+            continue;
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = moves.entry(next) {
+                queue.push_back((entry.key().clone(), count + 1));
+                entry.insert(count + 1);
+            }
+        }
+    }
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Cities with the cost of the roads between them (in both directions).
+    const ROADS: [(char, char, u32); 7] = [
+        ('a', 'b', 7),
+        ('a', 'c', 9),
+        ('a', 'f', 14),
+        ('b', 'c', 10),
+        ('b', 'd', 15),
+        ('c', 'd', 11),
+        ('c', 'f', 2),
+    ];
+
+    fn roads(city: &char) -> Vec<(char, u32)> {
+        ROADS
+            .iter()
+            .filter_map(|&(x, y, cost)| match *city {
+                c if c == x => Some((y, cost)),
+                c if c == y => Some((x, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn weighted_searches_find_the_cheapest_path() {
+        let path = dijkstra('a', roads, |x| *x == 'f').unwrap();
+        assert_eq!(path.cost, 11);
+        assert_eq!(path.states, ['a', 'c', 'f']);
+        assert_eq!(dijkstra('a', roads, |x| *x == 'a').unwrap().states, ['a']);
+        assert_eq!(dijkstra('a', roads, |x| *x == 'z'), None);
+
+        let heuristic = |x: &char| if *x == 'd' { 0 } else { 5 };
+        let path = astar('a', roads, heuristic, |x| *x == 'd').unwrap();
+        assert_eq!(path.cost, 20);
+        assert_eq!(path.states, ['a', 'c', 'd']);
+    }
+
+    #[test]
+    fn bfs_finds_the_fewest_moves() {
+        // Numbers can be incremented, or doubled.
+        let moves = |x: &u32| [x + 1, x * 2];
+        let path = bfs(1, moves, |x| *x == 10).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states, [1, 2, 4, 5, 10]);
+
+        let reached = bfs_within(1, 2, moves);
+        let mut reached: Vec<_> = reached.into_iter().collect();
+        reached.sort();
+        assert_eq!(reached, [(1, 0), (2, 1), (3, 2), (4, 2)]);
+    }
+
+    #[test]
+    fn cancelled_searches_give_up() {
+        let cancelled = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
+        crate::CANCELLED.set(Some(cancelled));
+        // There is no goal, and no end to the numbers to try.
+        let moves = |x: &u64| [x + 1, x * 2];
+        assert_eq!(bfs(1, moves, |_| false), None);
+        assert_eq!(dijkstra(1, |x| moves(x).map(|x| (x, 1)), |_| false), None);
+        assert_eq!(bfs_within(1, usize::MAX, moves).len(), 1);
+        crate::CANCELLED.set(None);
+    }
+}
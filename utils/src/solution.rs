@@ -0,0 +1,102 @@
+use crate::parse::{ParseError, ParseInput};
+
+/// The solutions of a day's puzzle: how its input is parsed and how both parts are solved.
+///
+/// Each day implements it on a unit struct, so that the runner, the benchmarks and the tests can
+/// call any day through the same interface:
+///
+/// ```ignore
+/// pub struct Day01;
+///
+/// impl Solution for Day01 {
+///     type Input = Input;
+///     type Answer = u32;
+///
+///     fn part_1(input: &Input) -> u32 { ... }
+///     fn part_2(input: &Input) -> u32 { ... }
+/// }
+/// ```
+pub trait Solution {
+    /// Shared with the worker threads solving the parts when there is a time limit.
+    type Input: ParseInput + std::fmt::Debug + Send + Sync + 'static;
+    type Answer: Into<crate::Answer> + 'static;
+
+    /// Builds the input from its text, with `ParseInput` by default.
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        Self::Input::parse(s)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Answer;
+
+    fn part_2(input: &Self::Input) -> Self::Answer;
+
+    /// Checks the properties of the input that the parts rely on, beyond what parsing checks. The
+    /// runner warns before solving a part whose assumptions don't hold.
+    fn validate(_input: &Self::Input) -> Vec<Check> {
+        Vec::new()
+    }
+
+    /// Both parts, in order.
+    fn parts() -> [Part<Self>; 2] {
+        [Self::part_1, Self::part_2]
+    }
+}
+
+/// A function solving one part of `S`.
+pub type Part<S> = fn(&<S as Solution>::Input) -> <S as Solution>::Answer;
+
+/// An assumption of some parts about their input, and whether it holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    /// The parts relying on it, as 1-based numbers.
+    pub parts: Vec<usize>,
+    pub description: String,
+    /// Why the assumption doesn't hold, or `None` if it does.
+    pub failure: Option<String>,
+}
+
+impl Check {
+    pub fn new(parts: &[usize], description: &str, result: Result<(), String>) -> Self {
+        Self {
+            parts: parts.to_vec(),
+            description: String::from(description),
+            failure: result.err(),
+        }
+    }
+
+    pub fn holds(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl std::fmt::Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = self.parts.iter().map(|x| x.to_string()).collect();
+        write!(
+            f,
+            "{} (part{} {})",
+            self.description,
+            if parts.len() > 1 { "s" } else { "" },
+            parts.join(", ")
+        )?;
+        if let Some(failure) = &self.failure {
+            write!(f, ": {failure}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_display() {
+        let check = Check::new(&[2], "the grid is square", Err(String::from("it is 3x4")));
+        assert!(!check.holds());
+        assert_eq!(check.to_string(), "the grid is square (part 2): it is 3x4");
+        let check = Check::new(&[1, 2], "the start is unique", Ok(()));
+        assert!(check.holds());
+        assert_eq!(check.to_string(), "the start is unique (parts 1, 2)");
+    }
+}
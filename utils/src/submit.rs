@@ -0,0 +1,513 @@
+//! Submitting answers to the puzzle website, keeping a history of the attempts of each day.
+//!
+//! The history is used to refuse answers that cannot be right before sending them: answers that
+//! were already rejected, answers outside the bounds given by "too high" and "too low" feedback,
+//! answers to parts that are already solved, and any answer while the website asks to wait.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::http::{form_encode, HttpError, Website};
+use crate::parse::{ParseError, ParseErrorKind, ParseInput};
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying in which direction.
+    Wrong,
+    /// The previous answer was submitted too recently, so this one was not checked.
+    TooRecent,
+    /// The part is already solved, or the first part is not yet.
+    AlreadySolved,
+    /// The response could not be understood.
+    Unknown,
+}
+
+impl Outcome {
+    const NAMES: [(Self, &'static str); 7] = [
+        (Self::Correct, "correct"),
+        (Self::TooHigh, "too-high"),
+        (Self::TooLow, "too-low"),
+        (Self::Wrong, "wrong"),
+        (Self::TooRecent, "too-recent"),
+        (Self::AlreadySolved, "already-solved"),
+        (Self::Unknown, "unknown"),
+    ];
+
+    pub fn name(&self) -> &'static str {
+        Self::NAMES.iter().find(|x| x.0 == *self).unwrap().1
+    }
+
+    /// Whether the answer was checked and found wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+impl std::str::FromStr for Outcome {
+    type Err = ParseErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::NAMES
+            .iter()
+            .find(|x| x.1 == s)
+            .map(|x| x.0)
+            .ok_or_else(|| ParseErrorKind::InvalidValue(String::from(s)))
+    }
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feedback {
+    pub outcome: Outcome,
+    /// How long to wait before submitting another answer, if the website said so.
+    pub wait: Option<Duration>,
+    /// The text of the response, without its markup.
+    pub message: String,
+}
+
+impl Feedback {
+    /// Reads the feedback from the page returned by the website.
+    pub fn parse(html: &str) -> Self {
+        let article = html
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(html, |(article, _)| article);
+        let message = strip_tags(article);
+        let text = message.to_lowercase();
+        let outcome = if text.contains("that's the right answer") {
+            Outcome::Correct
+        } else if text.contains("that's not the right answer") {
+            if text.contains("too high") {
+                Outcome::TooHigh
+            } else if text.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("you gave an answer too recently") {
+            Outcome::TooRecent
+        } else if text.contains("solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        };
+        Self {
+            outcome,
+            wait: parse_wait(&text),
+            message,
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Finds how long to wait in a lowercase message, from either "you have 1m 5s left to wait" or
+/// "please wait one minute" / "please wait 5 minutes".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let (_, left) = before.rsplit_once("you have ")?;
+        let mut secs = 0;
+        for word in left.split_whitespace() {
+            let unit = match word.chars().last()? {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            secs += unit * word[..word.len() - 1].parse::<u64>().ok()?;
+        }
+        return Some(Duration::from_secs(secs));
+    }
+    let (_, rest) = text.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let count = match words.next()? {
+        "one" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        count => count.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(60 * count))
+}
+
+/// An answer that was submitted, and what the website made of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// When it was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+    pub part: usize,
+    pub outcome: Outcome,
+    pub wait: Option<Duration>,
+    /// The answer, as the runner displays it.
+    pub answer: String,
+}
+
+/// Why an answer was not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved, with the given answer.
+    AlreadySolved(String),
+    /// The same answer was already submitted and found wrong.
+    KnownWrong,
+    /// An answer at most this high was already found too high.
+    AtLeast(i128),
+    /// An answer at least this low was already found too low.
+    AtMost(i128),
+    /// The website asked to wait before submitting again.
+    Throttled(Duration),
+}
+
+impl std::fmt::Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadySolved(answer) => write!(f, "the part was already solved with '{answer}'"),
+            Self::KnownWrong => write!(f, "the answer was already submitted and is wrong"),
+            Self::AtLeast(bound) => {
+                write!(f, "the answer is not below '{bound}', which is too high")
+            }
+            Self::AtMost(bound) => write!(f, "the answer is not above '{bound}', which is too low"),
+            Self::Throttled(wait) => write!(
+                f,
+                "the website asked to wait {}s before submitting again",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+/// The attempts made for a day, stored next to its input with one line per attempt:
+///
+/// ```text
+/// # time part outcome wait answer
+/// 1701406800 1 too-low 60 5432
+/// 1701406900 1 correct - 54321
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+const HISTORY_HEADER: &str = "# time part outcome wait answer";
+
+impl History {
+    /// The path of the history of `day` of `year` in `data_dir`.
+    pub fn path(data_dir: &Path, year: u32, day: u32) -> PathBuf {
+        data_dir
+            .join(year.to_string())
+            .join(format!("day{day:02}/submissions.log"))
+    }
+
+    /// Reads the history at `path`, which is empty if the file doesn't exist yet.
+    pub fn read(path: &Path) -> Result<Self, SubmitError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|error| SubmitError::History(path.into(), error))
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(SubmitError::Io(error)),
+        }
+    }
+
+    /// Appends `attempt` to the history at `path`, creating it if needed.
+    pub fn append(path: &Path, attempt: &Attempt) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let is_new = !path.exists();
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(path)?;
+        if is_new {
+            writeln!(file, "{HISTORY_HEADER}")?;
+        }
+        let wait = attempt
+            .wait
+            .map_or_else(|| String::from("-"), |x| x.as_secs().to_string());
+        writeln!(
+            file,
+            "{} {} {} {wait} {}",
+            attempt.time,
+            attempt.part,
+            attempt.outcome.name(),
+            attempt.answer
+        )
+    }
+
+    /// Checks whether `answer` could be right for `part` at time `now` (in seconds since the Unix
+    /// epoch), given the previous attempts.
+    pub fn check(&self, part: usize, answer: &str, now: u64) -> Result<(), Refusal> {
+        let attempts = || self.attempts.iter().filter(|x| x.part == part);
+        if let Some(solved) = attempts().find(|x| x.outcome == Outcome::Correct) {
+            return Err(Refusal::AlreadySolved(solved.answer.clone()));
+        }
+        if attempts().any(|x| x.outcome.is_wrong() && x.answer == answer) {
+            return Err(Refusal::KnownWrong);
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome| {
+                attempts()
+                    .filter(move |x| x.outcome == outcome)
+                    .filter_map(|x| x.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(Outcome::TooHigh).min().filter(|x| value >= *x) {
+                return Err(Refusal::AtLeast(high));
+            }
+            if let Some(low) = bound(Outcome::TooLow).max().filter(|x| value <= *x) {
+                return Err(Refusal::AtMost(low));
+            }
+        }
+        let until = self
+            .attempts
+            .iter()
+            .filter_map(|x| x.wait.map(|wait| x.time + wait.as_secs()))
+            .max();
+        match until {
+            Some(until) if until > now => Err(Refusal::Throttled(Duration::from_secs(until - now))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ParseInput for History {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut attempts = Vec::new();
+        for line in crate::parse::lines(s) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let (time, rest) = line.split_once(text, " ")?;
+            let (part, rest) = line.split_once(rest, " ")?;
+            let (outcome, rest) = line.split_once(rest, " ")?;
+            let (wait, answer) = line.split_once(rest, " ")?;
+            attempts.push(Attempt {
+                time: line.parse(time)?,
+                part: line.parse(part)?,
+                outcome: outcome.parse().map_err(|kind| line.error(outcome, kind))?,
+                wait: match wait {
+                    "-" => None,
+                    wait => Some(Duration::from_secs(line.parse(wait)?)),
+                },
+                answer: String::from(answer),
+            });
+        }
+        Ok(Self { attempts })
+    }
+}
+
+/// Why an answer could not be submitted.
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Http(HttpError),
+    /// The history file is invalid.
+    History(PathBuf, ParseError),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Refused(refusal) => write!(f, "not submitted, as {refusal}"),
+            Self::Http(error) => write!(f, "{error}"),
+            Self::History(path, error) => write!(f, "failed to parse {path:?} at {error}"),
+            Self::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<std::io::Error> for SubmitError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Submits answers, recording every attempt in the history of its day.
+pub struct Submitter {
+    data_dir: PathBuf,
+    website: Website,
+}
+
+impl Submitter {
+    pub fn new(data_dir: impl Into<PathBuf>, website: Website) -> Self {
+        Self {
+            data_dir: data_dir.into(),
+            website,
+        }
+    }
+
+    /// Uses the workspace data directory, and the website set in the environment.
+    pub fn from_env() -> Self {
+        Self::new(crate::data_dir(), Website::from_env())
+    }
+
+    pub fn history_path(&self, year: u32, day: u32) -> PathBuf {
+        History::path(&self.data_dir, year, day)
+    }
+
+    /// Submits `answer` to `part` of `day` of `year`, unless the history shows it cannot be right.
+    pub fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: usize,
+        answer: &crate::Answer,
+    ) -> Result<Feedback, SubmitError> {
+        let answer = answer.to_string();
+        let path = self.history_path(year, day);
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs());
+        History::read(&path)?
+            .check(part, &answer, now)
+            .map_err(SubmitError::Refused)?;
+
+        let form = format!("level={part}&answer={}", form_encode(&answer));
+        let response = self
+            .website
+            .post(&format!("/{year}/day/{day}/answer"), &form)
+            .map_err(SubmitError::Http)?;
+        let feedback = Feedback::parse(&response.body);
+        let attempt = Attempt {
+            time: now,
+            part,
+            outcome: feedback.outcome,
+            wait: feedback.wait,
+            answer,
+        };
+        History::append(&path, &attempt)?;
+        Ok(feedback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::TcpClient;
+    use crate::testing::MockServer;
+
+    fn page(message: &str) -> String {
+        format!("<html><main>\n<article><p>{message}</p></article>\n</main></html>")
+    }
+
+    #[test]
+    fn parses_feedback() {
+        let feedback = Feedback::parse(&page(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+             you're using the full input data. Please wait one minute before trying again. \
+             <a href=\"/2023/day/1\">[Return to Day 1]</a>",
+        ));
+        assert_eq!(feedback.outcome, Outcome::TooHigh);
+        assert_eq!(feedback.wait, Some(Duration::from_secs(60)));
+        assert!(feedback
+            .message
+            .ends_with("trying again. [Return to Day 1]"));
+
+        let feedback = Feedback::parse(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 1m 5s left to wait.",
+        ));
+        assert_eq!(feedback.outcome, Outcome::TooRecent);
+        assert_eq!(feedback.wait, Some(Duration::from_secs(65)));
+
+        let outcome = |message| Feedback::parse(&page(message)).outcome;
+        assert_eq!(
+            outcome("That's the right answer! You are one gold star closer."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(outcome("That's not the right answer."), Outcome::Wrong);
+        assert_eq!(
+            outcome("You don't seem to be solving the right level.  Did you already complete it?"),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(outcome("Something else"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn refuses_answers_that_cannot_be_right() {
+        let history = History::parse(
+            "# time part outcome wait answer
+             100 1 too-high 60 500
+             200 1 too-low 60 100
+             300 1 wrong 300 250
+             400 2 correct - 42",
+        )
+        .unwrap();
+        assert_eq!(history.attempts.len(), 4);
+        assert_eq!(history.check(1, "250", 1000), Err(Refusal::KnownWrong));
+        assert_eq!(history.check(1, "600", 1000), Err(Refusal::AtLeast(500)));
+        assert_eq!(history.check(1, "50", 1000), Err(Refusal::AtMost(100)));
+        assert_eq!(
+            history.check(1, "300", 500),
+            Err(Refusal::Throttled(Duration::from_secs(100)))
+        );
+        assert_eq!(history.check(1, "300", 1000), Ok(()));
+        assert_eq!(
+            history.check(2, "43", 1000),
+            Err(Refusal::AlreadySolved(String::from("42")))
+        );
+        assert!(History::parse("100 1 maybe - 5").is_err());
+    }
+
+    #[test]
+    fn records_submissions() {
+        let server = MockServer::start(&[(
+            "/2023/day/1/answer",
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        let website = Website::new(
+            &server.url(),
+            Some(String::from("token")),
+            Box::new(TcpClient),
+        );
+        let submitter = Submitter::new(&dir, website);
+
+        let feedback = submitter
+            .submit(2023, 1, 2, &crate::Answer::from(17))
+            .unwrap();
+        assert_eq!(feedback.outcome, Outcome::TooLow);
+        assert!(matches!(
+            submitter.submit(2023, 1, 2, &crate::Answer::from(12)),
+            Err(SubmitError::Refused(Refusal::AtMost(17)))
+        ));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].body, "level=2&answer=17");
+
+        let history = History::read(&submitter.history_path(2023, 1)).unwrap();
+        assert_eq!(history.attempts.len(), 1);
+        assert_eq!(history.attempts[0].outcome, Outcome::TooLow);
+        assert_eq!(history.attempts[0].answer, "17");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,196 @@
+//! Regression tests checking the solutions against the stored answers files.
+//!
+//! Each day registers its inputs with [`answer_tests!`](crate::answer_tests), which generates one
+//! test per input:
+//!
+//! ```ignore
+//! utils::answer_tests! {
+//!     Day01;
+//!     sample_input => "day01/sample_input.txt",
+//!     #[ignore = "slow in debug builds"]
+//!     input => "day01/input.txt",
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::{Answer, ExpectedAnswers, InputSource, ParseInput, Solution, Verdict};
+
+/// Reads and parses the input at `input_path` (relative to the data directory of `year`), for tests
+/// of the helpers of a day. Panics if it can't.
+pub fn parse_input<S: Solution>(year: u32, input_path: &str) -> S::Input {
+    let source = InputSource::from_path(input_path, Some(year));
+    let input_text = source
+        .read_to_string()
+        .unwrap_or_else(|error| panic!("Failed to read {source}: {error}"));
+    S::parse(&input_text).unwrap_or_else(|error| panic!("Failed to parse {source} at {error}"))
+}
+
+/// Runs the solution on the input at `input_path` (relative to the data directory of `year`) and
+/// panics unless every answer matches the one stored in its answers file. Parts without an expected
+/// answer are skipped, but at least one of them must be checked.
+pub fn check_answers<S: Solution>(year: u32, input_path: &str) {
+    let source = InputSource::from_path(input_path, Some(year));
+    let input = parse_input::<S>(year, input_path);
+
+    let answers_path = ExpectedAnswers::path(&source).unwrap();
+    let answers_text = std::fs::read_to_string(&answers_path)
+        .unwrap_or_else(|error| panic!("Failed to read {answers_path:?}: {error}"));
+    let expected_answers = ExpectedAnswers::parse(&answers_text)
+        .unwrap_or_else(|error| panic!("Failed to parse {answers_path:?} at {error}"));
+    assert!(
+        !expected_answers.is_empty(),
+        "No expected answers in {answers_path:?}"
+    );
+
+    let mut failures = Vec::new();
+    for (i, solution) in S::parts().iter().enumerate() {
+        let Some(expected) = expected_answers.get(i + 1) else {
+            continue;
+        };
+        let answer: Answer = solution(&input).into();
+        if let verdict @ Verdict::Fail { .. } = Verdict::new(Some(expected), &answer) {
+            failures.push(format!("part {}: got '{answer}', {verdict}", i + 1));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "Wrong answers for {source}:\n{}",
+        failures.join("\n")
+    );
+}
+
+/// Generates a test for each listed input, checking the solution against its answers file.
+/// Attributes (such as `#[ignore]`) can be put before an input to apply them to its test. The paths
+/// are relative to the data directory of the year of the crate's `DAY`.
+#[macro_export]
+macro_rules! answer_tests {
+    ($solution:ident; $($(#[$attr:meta])* $name:ident => $path:literal),* $(,)?) => {
+        #[cfg(test)]
+        mod answer_tests {
+            fn check(input_path: &str) {
+                $crate::testing::check_answers::<super::$solution>(super::DAY.year, input_path);
+            }
+
+            $(
+                #[test]
+                $(#[$attr])*
+                fn $name() {
+                    check($path);
+                }
+            )*
+        }
+    };
+}
+
+/// A request received by a [`MockServer`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MockRequest {
+    pub method: String,
+    pub path: String,
+    /// The `Cookie` header, or an empty string without one.
+    pub cookie: String,
+    pub body: String,
+}
+
+/// A local HTTP server answering requests with canned responses, to test code that talks to the
+/// puzzle website without reaching it. It serves on a background thread until dropped.
+pub struct MockServer {
+    address: std::net::SocketAddr,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Serves the given `(path, status, body)` routes whatever the method, and answers 404 to any
+    /// other path.
+    pub fn start(routes: &[(&str, u16, &str)]) -> Self {
+        use std::io::{BufRead, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let routes: Vec<(String, u16, String)> = routes
+            .iter()
+            .map(|(path, status, body)| (String::from(*path), *status, String::from(*body)))
+            .collect();
+        let requests: Arc<Mutex<Vec<MockRequest>>> = Arc::default();
+        let stop: Arc<AtomicBool> = Arc::default();
+
+        let thread = {
+            let (requests, stop) = (Arc::clone(&requests), Arc::clone(&stop));
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    let Ok(mut stream) = stream else {
+                        continue;
+                    };
+                    let mut reader = std::io::BufReader::new(&stream);
+                    let mut request = MockRequest::default();
+                    let mut content_length = 0;
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).is_ok_and(|n| n > 0) {
+                        let text = line.trim_end();
+                        if text.is_empty() {
+                            break;
+                        } else if request.method.is_empty() {
+                            let mut words = text.split(' ');
+                            request.method = String::from(words.next().unwrap_or_default());
+                            request.path = String::from(words.next().unwrap_or_default());
+                        } else if let Some(cookie) = text.strip_prefix("Cookie: ") {
+                            request.cookie = String::from(cookie);
+                        } else if let Some(length) = text.strip_prefix("Content-Length: ") {
+                            content_length = length.parse().unwrap_or(0);
+                        }
+                        line.clear();
+                    }
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).ok();
+                    request.body = String::from_utf8_lossy(&body).into_owned();
+
+                    let (status, body) = routes
+                        .iter()
+                        .find(|route| route.0 == request.path)
+                        .map_or((404, ""), |route| (route.1, &route.2[..]));
+                    requests.lock().unwrap().push(request);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .ok();
+                }
+            })
+        };
+        Self {
+            address,
+            requests,
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    /// The base URL of the server, without a trailing slash.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        // Wakes up the server thread, which is waiting for a connection.
+        std::net::TcpStream::connect(self.address).ok();
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
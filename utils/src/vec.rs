@@ -0,0 +1,293 @@
+use crate::parse::ParseErrorKind;
+
+/// A vector of `N` integer coordinates, used both for positions and offsets.
+///
+/// Vectors are ordered by their first coordinate, then by the second one, and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector<const N: usize>(pub [i64; N]);
+
+pub type Vec2i = Vector<2>;
+pub type Vec3i = Vector<3>;
+
+impl<const N: usize> Vector<N> {
+    pub const ZERO: Self = Self([0; N]);
+
+    /// Sum of the products of the coordinates.
+    pub fn dot(self, rhs: Self) -> i64 {
+        self.0.iter().zip(rhs.0).map(|(a, b)| a * b).sum()
+    }
+
+    /// Sum of the absolute values of the coordinates (the taxicab length).
+    pub fn manhattan(self) -> i64 {
+        self.0.iter().map(|x| x.abs()).sum()
+    }
+
+    /// Largest absolute value of the coordinates (the king-move length).
+    pub fn chebyshev(self) -> i64 {
+        self.0.iter().map(|x| x.abs()).max().unwrap_or(0)
+    }
+
+    /// The sign (-1, 0 or 1) of each coordinate, which turns an offset along an axis or a
+    /// diagonal into a unit step.
+    pub fn signum(self) -> Self {
+        Self(self.0.map(i64::signum))
+    }
+
+    pub fn abs(self) -> Self {
+        Self(self.0.map(i64::abs))
+    }
+
+    /// Coordinate-wise minimum.
+    pub fn min(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i].min(rhs.0[i])))
+    }
+
+    /// Coordinate-wise maximum.
+    pub fn max(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i].max(rhs.0[i])))
+    }
+}
+
+impl Vector<2> {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self([x, y])
+    }
+
+    pub const fn x(self) -> i64 {
+        self.0[0]
+    }
+
+    pub const fn y(self) -> i64 {
+        self.0[1]
+    }
+
+    /// The z coordinate of the cross product of the vectors extended to 3D, i.e. twice the signed
+    /// area of the triangle they span (positive when `rhs` is counterclockwise from `self`).
+    pub fn cross(self, rhs: Self) -> i64 {
+        self.x() * rhs.y() - self.y() * rhs.x()
+    }
+}
+
+impl Vector<3> {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self([x, y, z])
+    }
+
+    pub const fn x(self) -> i64 {
+        self.0[0]
+    }
+
+    pub const fn y(self) -> i64 {
+        self.0[1]
+    }
+
+    pub const fn z(self) -> i64 {
+        self.0[2]
+    }
+
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y() * rhs.z() - self.z() * rhs.y(),
+            self.z() * rhs.x() - self.x() * rhs.z(),
+            self.x() * rhs.y() - self.y() * rhs.x(),
+        )
+    }
+}
+
+impl<const N: usize> Default for Vector<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> From<[i64; N]> for Vector<N> {
+    fn from(coords: [i64; N]) -> Self {
+        Self(coords)
+    }
+}
+
+impl<const N: usize> std::ops::Index<usize> for Vector<N> {
+    type Output = i64;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.0[idx]
+    }
+}
+
+impl<const N: usize> std::ops::IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.0[idx]
+    }
+}
+
+impl<const N: usize> std::ops::AddAssign for Vector<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<const N: usize> std::ops::Add for Vector<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize> std::ops::SubAssign for Vector<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a -= b);
+    }
+}
+
+impl<const N: usize> std::ops::Sub for Vector<N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> std::ops::MulAssign<i64> for Vector<N> {
+    fn mul_assign(&mut self, rhs: i64) {
+        self.0.iter_mut().for_each(|a| *a *= rhs);
+    }
+}
+
+impl<const N: usize> std::ops::Mul<i64> for Vector<N> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: i64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<const N: usize> std::ops::Mul<Vector<N>> for i64 {
+    type Output = Vector<N>;
+
+    fn mul(self, rhs: Vector<N>) -> Self::Output {
+        rhs * self
+    }
+}
+
+/// Divides each coordinate, rounding towards zero.
+impl<const N: usize> std::ops::DivAssign<i64> for Vector<N> {
+    fn div_assign(&mut self, rhs: i64) {
+        self.0.iter_mut().for_each(|a| *a /= rhs);
+    }
+}
+
+impl<const N: usize> std::ops::Div<i64> for Vector<N> {
+    type Output = Self;
+
+    fn div(mut self, rhs: i64) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl<const N: usize> std::ops::Neg for Vector<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(|x| -x))
+    }
+}
+
+impl<const N: usize> std::iter::Sum for Vector<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+/// Formats the coordinates separated by commas, as accepted by `FromStr`.
+impl<const N: usize> std::fmt::Display for Vector<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, x) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses exactly `N` comma-separated coordinates, ignoring the spaces around them
+/// (e.g. `1,2,3` or `19, 13, -30`).
+impl<const N: usize> std::str::FromStr for Vector<N> {
+    type Err = ParseErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coords = [0; N];
+        let mut parts = s.split(',');
+        for coord in &mut coords {
+            let part = parts.next().ok_or_else(|| {
+                ParseErrorKind::Other(format!("expected {N} coordinates in '{s}'"))
+            })?;
+            *coord = part
+                .trim()
+                .parse()
+                .map_err(|_| ParseErrorKind::InvalidValue(String::from(part.trim())))?;
+        }
+        if parts.next().is_some() {
+            return Err(ParseErrorKind::Other(format!(
+                "expected {N} coordinates in '{s}'"
+            )));
+        }
+        Ok(Self(coords))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators() {
+        let a = Vec3i::new(1, -2, 3);
+        let b = Vec3i::new(4, 5, -6);
+        assert_eq!(a + b, Vec3i::new(5, 3, -3));
+        assert_eq!(a - b, Vec3i::new(-3, -7, 9));
+        assert_eq!(-a, Vec3i::new(-1, 2, -3));
+        assert_eq!(2 * a, a * 2);
+        assert_eq!(b / 4, Vec3i::new(1, 1, -1));
+        assert_eq!([a, b, a].into_iter().sum::<Vec3i>(), Vec3i::new(6, 1, 0));
+    }
+
+    #[test]
+    fn products_and_norms() {
+        let a = Vec3i::new(1, -2, 3);
+        let b = Vec3i::new(4, 5, -6);
+        assert_eq!(a.dot(b), -24);
+        assert_eq!(a.cross(b), Vec3i::new(-3, 18, 13));
+        assert_eq!(a.cross(b).dot(a), 0);
+        assert_eq!(Vec2i::new(1, 0).cross(Vec2i::new(0, 1)), 1);
+        assert_eq!(a.manhattan(), 6);
+        assert_eq!(a.chebyshev(), 3);
+        assert_eq!(Vector::<0>::ZERO.chebyshev(), 0);
+    }
+
+    #[test]
+    fn ordering_is_lexicographic() {
+        let mut v = vec![Vec2i::new(1, 0), Vec2i::new(0, 5), Vec2i::new(0, 1)];
+        v.sort();
+        assert_eq!(v, [Vec2i::new(0, 1), Vec2i::new(0, 5), Vec2i::new(1, 0)]);
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        assert_eq!("19, 13, -30".parse(), Ok(Vec3i::new(19, 13, -30)));
+        let v = Vec3i::new(1, -2, 3);
+        assert_eq!(v.to_string().parse(), Ok(v));
+        assert!("1,2".parse::<Vec3i>().is_err());
+        assert!("1,2,3,4".parse::<Vec3i>().is_err());
+        assert_eq!(
+            "1,x".parse::<Vec2i>(),
+            Err(ParseErrorKind::InvalidValue(String::from("x")))
+        );
+    }
+}